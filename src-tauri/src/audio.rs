@@ -0,0 +1,197 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use once_cell::sync::Lazy;
+
+/// Whether an `AudioRecorder` anywhere in the process currently has a
+/// stream open, so `commands::start_audio_level_emitter` knows whether
+/// `current_level()` is reporting live audio or stale silence.
+static RECORDING: AtomicBool = AtomicBool::new(false);
+/// `(rms_db, peak_db)` of the most recently captured chunk, updated from
+/// the CPAL input callback.
+static LEVEL: Lazy<Mutex<(f32, f32)>> = Lazy::new(|| Mutex::new((MIN_DB, MIN_DB)));
+
+const MIN_DB: f32 = -100.0;
+
+/// Whether any `AudioRecorder` is currently capturing.
+pub fn is_recording() -> bool {
+  RECORDING.load(Ordering::SeqCst)
+}
+
+/// The `(rms_db, peak_db)` of the most recently captured audio chunk, for
+/// the VAD tracker and the `audio:level` UI meter. Reports `(MIN_DB,
+/// MIN_DB)` when nothing is recording.
+pub fn current_level() -> (f32, f32) {
+  *LEVEL.lock().unwrap()
+}
+
+/// Captures microphone audio via CPAL. `start`/`stop` buffer a whole take
+/// in memory and hand back WAV-encoded bytes for the one-shot transcribe
+/// flow; `start_chunked` instead streams raw PCM16 chunks to a callback as
+/// they're captured, for the streaming transcription flow.
+pub struct AudioRecorder {
+  stream: Mutex<Option<cpal::Stream>>,
+  buffer: Arc<Mutex<Vec<i16>>>,
+  sample_rate: Mutex<u32>,
+  channels: Mutex<u16>,
+}
+
+impl Default for AudioRecorder {
+  fn default() -> Self {
+    Self {
+      stream: Mutex::new(None),
+      buffer: Arc::new(Mutex::new(Vec::new())),
+      sample_rate: Mutex::new(16_000),
+      channels: Mutex::new(1),
+    }
+  }
+}
+
+impl AudioRecorder {
+  pub fn start(&self) -> Result<(), String> {
+    self.buffer.lock().map_err(|_| "Audio buffer lock poisoned".to_string())?.clear();
+
+    let buffer = self.buffer.clone();
+    let stream = self.build_stream(move |samples: &[i16]| {
+      if let Ok(mut buffer) = buffer.lock() {
+        buffer.extend_from_slice(samples);
+      }
+    })?;
+    stream.play().map_err(|e| format!("Failed to start audio stream: {e}"))?;
+
+    *self.stream.lock().map_err(|_| "Audio stream lock poisoned".to_string())? = Some(stream);
+    RECORDING.store(true, Ordering::SeqCst);
+    Ok(())
+  }
+
+  pub fn stop(&self) -> Result<Vec<u8>, String> {
+    self
+      .stream
+      .lock()
+      .map_err(|_| "Audio stream lock poisoned".to_string())?
+      .take();
+    RECORDING.store(false, Ordering::SeqCst);
+    *LEVEL.lock().unwrap() = (MIN_DB, MIN_DB);
+
+    let samples = self.buffer.lock().map_err(|_| "Audio buffer lock poisoned".to_string())?;
+    let sample_rate = *self.sample_rate.lock().map_err(|_| "Sample rate lock poisoned".to_string())?;
+    let channels = *self.channels.lock().map_err(|_| "Channel count lock poisoned".to_string())?;
+    Ok(encode_wav(&samples, sample_rate, channels))
+  }
+
+  /// Streams raw little-endian PCM16 chunks to `on_chunk` as they're
+  /// captured, instead of buffering the whole take for a single WAV.
+  pub fn start_chunked(&self, mut on_chunk: Box<dyn FnMut(Vec<u8>) + Send>) -> Result<(), String> {
+    let stream = self.build_stream(move |samples: &[i16]| {
+      let mut bytes = Vec::with_capacity(samples.len() * 2);
+      for sample in samples {
+        bytes.extend_from_slice(&sample.to_le_bytes());
+      }
+      on_chunk(bytes);
+    })?;
+    stream.play().map_err(|e| format!("Failed to start audio stream: {e}"))?;
+
+    *self.stream.lock().map_err(|_| "Audio stream lock poisoned".to_string())? = Some(stream);
+    RECORDING.store(true, Ordering::SeqCst);
+    Ok(())
+  }
+
+  fn build_stream(&self, mut on_samples: impl FnMut(&[i16]) + Send + 'static) -> Result<cpal::Stream, String> {
+    let host = cpal::default_host();
+    let device = host.default_input_device().ok_or("No input audio device available".to_string())?;
+    let config = device
+      .default_input_config()
+      .map_err(|e| format!("Failed to read input device config: {e}"))?;
+
+    *self.sample_rate.lock().map_err(|_| "Sample rate lock poisoned".to_string())? = config.sample_rate().0;
+    *self.channels.lock().map_err(|_| "Channel count lock poisoned".to_string())? = config.channels();
+
+    let err_fn = |err| eprintln!("Audio stream error: {err}");
+
+    let stream = match config.sample_format() {
+      cpal::SampleFormat::I16 => device.build_input_stream(
+        &config.into(),
+        move |data: &[i16], _: &cpal::InputCallbackInfo| {
+          update_level(data);
+          on_samples(data);
+        },
+        err_fn,
+        None,
+      ),
+      cpal::SampleFormat::F32 => device.build_input_stream(
+        &config.into(),
+        move |data: &[f32], _: &cpal::InputCallbackInfo| {
+          let samples: Vec<i16> = data
+            .iter()
+            .map(|sample| (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)
+            .collect();
+          update_level(&samples);
+          on_samples(&samples);
+        },
+        err_fn,
+        None,
+      ),
+      other => return Err(format!("Unsupported input sample format: {other:?}")),
+    }
+    .map_err(|e| format!("Failed to build audio input stream: {e}"))?;
+
+    Ok(stream)
+  }
+}
+
+/// Computes this chunk's RMS/peak level in dBFS and publishes it to
+/// `current_level()`.
+fn update_level(samples: &[i16]) {
+  if samples.is_empty() {
+    return;
+  }
+
+  let mut sum_squares = 0f64;
+  let mut peak: u16 = 0;
+  for &sample in samples {
+    sum_squares += (sample as f64) * (sample as f64);
+    peak = peak.max(sample.unsigned_abs());
+  }
+
+  let rms = (sum_squares / samples.len() as f64).sqrt();
+  let rms_db = amplitude_to_db(rms / i16::MAX as f64) as f32;
+  let peak_db = amplitude_to_db(peak as f64 / i16::MAX as f64) as f32;
+
+  *LEVEL.lock().unwrap() = (rms_db, peak_db);
+}
+
+fn amplitude_to_db(amplitude: f64) -> f64 {
+  if amplitude <= 0.0 {
+    MIN_DB as f64
+  } else {
+    (20.0 * amplitude.log10()).max(MIN_DB as f64)
+  }
+}
+
+/// Wraps raw PCM16 samples in a minimal 44-byte canonical WAV header.
+fn encode_wav(samples: &[i16], sample_rate: u32, channels: u16) -> Vec<u8> {
+  let bits_per_sample: u16 = 16;
+  let byte_rate = sample_rate * channels as u32 * bits_per_sample as u32 / 8;
+  let block_align = channels * bits_per_sample / 8;
+  let data_len = (samples.len() * 2) as u32;
+
+  let mut out = Vec::with_capacity(44 + samples.len() * 2);
+  out.extend_from_slice(b"RIFF");
+  out.extend_from_slice(&(36 + data_len).to_le_bytes());
+  out.extend_from_slice(b"WAVE");
+  out.extend_from_slice(b"fmt ");
+  out.extend_from_slice(&16u32.to_le_bytes());
+  out.extend_from_slice(&1u16.to_le_bytes());
+  out.extend_from_slice(&channels.to_le_bytes());
+  out.extend_from_slice(&sample_rate.to_le_bytes());
+  out.extend_from_slice(&byte_rate.to_le_bytes());
+  out.extend_from_slice(&block_align.to_le_bytes());
+  out.extend_from_slice(&bits_per_sample.to_le_bytes());
+  out.extend_from_slice(b"data");
+  out.extend_from_slice(&data_len.to_le_bytes());
+  for sample in samples {
+    out.extend_from_slice(&sample.to_le_bytes());
+  }
+  out
+}
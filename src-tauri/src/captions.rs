@@ -0,0 +1,155 @@
+use crate::transcribe::TranscriptionSegment;
+
+/// Caption container format to export timestamped segments into.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptionFormat {
+  Srt,
+  WebVtt,
+}
+
+/// Formats transcription segments as an SRT caption file: `index`, then
+/// `HH:MM:SS,mmm --> HH:MM:SS,mmm`, then the cue text, each block
+/// separated by a blank line.
+pub fn to_srt(segments: &[TranscriptionSegment], duration_secs: Option<f64>) -> String {
+  format_cues(segments, duration_secs, format_srt_cue)
+}
+
+/// Formats transcription segments as a WebVTT caption file: the `WEBVTT`
+/// header, then cues using `.` as the millisecond separator.
+pub fn to_webvtt(segments: &[TranscriptionSegment], duration_secs: Option<f64>) -> String {
+  let mut output = String::from("WEBVTT\n\n");
+  output.push_str(&format_cues(segments, duration_secs, format_webvtt_cue));
+  output
+}
+
+/// Shared cue-building pass: skips segments with empty text, drops
+/// zero/negative-length segments, and clamps every cue's `end` to
+/// `duration_secs`, so a final cue the provider reports past the clip's
+/// length ends with the clip.
+fn format_cues(
+  segments: &[TranscriptionSegment],
+  duration_secs: Option<f64>,
+  format_cue: impl Fn(usize, f64, f64, &str) -> String,
+) -> String {
+  let mut output = String::new();
+  let mut index = 0usize;
+
+  for segment in segments {
+    let text = segment.text.trim();
+    if text.is_empty() {
+      continue;
+    }
+
+    let end = match duration_secs {
+      Some(duration) => segment.end.min(duration),
+      None => segment.end,
+    };
+
+    if end <= segment.start {
+      continue;
+    }
+
+    index += 1;
+    output.push_str(&format_cue(index, segment.start, end, text));
+  }
+
+  output
+}
+
+fn format_srt_cue(index: usize, start: f64, end: f64, text: &str) -> String {
+  format!(
+    "{index}\n{} --> {}\n{text}\n\n",
+    format_timestamp(start, ','),
+    format_timestamp(end, ',')
+  )
+}
+
+fn format_webvtt_cue(_index: usize, start: f64, end: f64, text: &str) -> String {
+  format!(
+    "{} --> {}\n{text}\n\n",
+    format_timestamp(start, '.'),
+    format_timestamp(end, '.')
+  )
+}
+
+fn format_timestamp(seconds: f64, ms_separator: char) -> String {
+  let total_ms = (seconds.max(0.0) * 1000.0).round() as u64;
+  let hours = total_ms / 3_600_000;
+  let minutes = (total_ms % 3_600_000) / 60_000;
+  let secs = (total_ms % 60_000) / 1_000;
+  let millis = total_ms % 1_000;
+  format!("{hours:02}:{minutes:02}:{secs:02}{ms_separator}{millis:03}")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn segment(start: f64, end: f64, text: &str) -> TranscriptionSegment {
+    TranscriptionSegment {
+      start,
+      end,
+      text: text.to_string(),
+    }
+  }
+
+  #[test]
+  fn srt_formats_index_and_arrow_timestamps() {
+    let segments = vec![segment(0.0, 1.5, "hello"), segment(1.5, 3.25, "world")];
+    let srt = to_srt(&segments, None);
+
+    assert_eq!(
+      srt,
+      "1\n00:00:00,000 --> 00:00:01,500\nhello\n\n2\n00:00:01,500 --> 00:00:03,250\nworld\n\n"
+    );
+  }
+
+  #[test]
+  fn webvtt_uses_the_header_and_dot_millisecond_separator() {
+    let segments = vec![segment(0.0, 1.5, "hello")];
+    let vtt = to_webvtt(&segments, None);
+
+    assert_eq!(vtt, "WEBVTT\n\n00:00:00.000 --> 00:00:01.500\nhello\n\n");
+  }
+
+  #[test]
+  fn skips_segments_with_empty_text() {
+    let segments = vec![segment(0.0, 1.0, "  "), segment(1.0, 2.0, "real line")];
+    let srt = to_srt(&segments, None);
+
+    assert_eq!(srt, "1\n00:00:01,000 --> 00:00:02,000\nreal line\n\n");
+  }
+
+  #[test]
+  fn skips_zero_length_segments() {
+    let segments = vec![segment(1.0, 1.0, "stuck"), segment(1.0, 2.0, "moves")];
+    let srt = to_srt(&segments, None);
+
+    assert_eq!(srt, "1\n00:00:01,000 --> 00:00:02,000\nmoves\n\n");
+  }
+
+  #[test]
+  fn clamps_the_final_cue_to_duration_secs() {
+    let segments = vec![segment(0.0, 1.0, "first"), segment(1.0, 9.0, "overruns the clip")];
+    let srt = to_srt(&segments, Some(5.0));
+
+    assert_eq!(
+      srt,
+      "1\n00:00:00,000 --> 00:00:01,000\nfirst\n\n2\n00:00:01,000 --> 00:00:05,000\noverruns the clip\n\n"
+    );
+  }
+
+  #[test]
+  fn an_entirely_out_of_range_final_cue_is_dropped() {
+    let segments = vec![segment(5.0, 6.0, "past the end")];
+    let srt = to_srt(&segments, Some(5.0));
+
+    assert_eq!(srt, "");
+  }
+
+  #[test]
+  fn hour_boundary_rolls_over_correctly() {
+    assert_eq!(format_timestamp(3_661.5, ','), "01:01:01,500");
+  }
+}
@@ -57,6 +57,30 @@ pub fn copy_and_paste(text: &str, restore_clipboard: bool) -> Result<(), String>
   Ok(())
 }
 
+/// Synthesizes `text` as literal keystrokes via enigo's Unicode text entry
+/// instead of a paste shortcut, so no clipboard round-trip happens at all.
+/// This reaches terminals, password-adjacent fields, and remote-desktop
+/// windows that block clipboard paste. `inter_char_delay_ms` gives slow
+/// receivers (VNC, some terminal emulators) time to keep up.
+pub fn type_text(text: &str, inter_char_delay_ms: u64) -> Result<(), String> {
+  let mut enigo = Enigo::new(&Settings::default()).map_err(|e| wrap_accessibility_error(e.to_string()))?;
+
+  if inter_char_delay_ms == 0 {
+    return enigo
+      .text(text)
+      .map_err(|e| wrap_accessibility_error(e.to_string()));
+  }
+
+  for ch in text.chars() {
+    enigo
+      .key(Key::Unicode(ch), Click)
+      .map_err(|e| wrap_accessibility_error(e.to_string()))?;
+    thread::sleep(Duration::from_millis(inter_char_delay_ms));
+  }
+
+  Ok(())
+}
+
 fn paste_modifier_key() -> Key {
   // macOS uses Command, Windows/Linux use Control.
   #[cfg(target_os = "macos")]
@@ -1,6 +1,6 @@
 use tauri::{AppHandle, Emitter, Manager, PhysicalPosition, State, WebviewWindow};
 
-use crate::domain::types::VocabularyEntry;
+use crate::domain::types::{HotkeyBinding, HotkeyMode, VocabularyEntry, VoiceInfo};
 use crate::settings::AppSettings;
 use crate::state::AppState;
 use crate::transcription_history::TranscriptionHistoryItem;
@@ -53,6 +53,10 @@ pub async fn stop_and_transcribe(
         })
         .await;
 
+    if let Ok(text) = &result {
+        crate::transcription_history::record_transcription(text, None, None);
+    }
+
     if let Some(message) = crate::transcription_history::take_runtime_error() {
         let _ = app.emit("transcription-history-error", message);
     }
@@ -64,6 +68,106 @@ pub async fn stop_and_transcribe(
     result
 }
 
+/// Aborts a transcription started by `stop_and_transcribe` while it's still
+/// waiting on the network, tearing down the in-flight HTTP request instead
+/// of letting it run to completion unused.
+#[tauri::command]
+pub fn cancel_transcription(window: WebviewWindow, state: State<'_, AppState>) -> Result<(), String> {
+    let window = window.clone();
+    state.manager.cancel(move |update| {
+        let _ = window.emit("dictation:update", update);
+    })
+}
+
+/// Starts the streaming dictation path. Raw partials from the provider are
+/// folded through a `ReconciliationBuffer` as they arrive: newly-committed
+/// text is pasted immediately via `paste_streamed_chunk`, while the
+/// uncommitted tail is only ever shown as a preview over `dictation:update`
+/// (state `Streaming`) and never pasted. `stop_streaming` ends capture;
+/// whatever is still uncommitted at that point is flushed and pasted too.
+#[tauri::command]
+pub fn start_streaming(window: WebviewWindow, state: State<'_, AppState>) -> Result<(), String> {
+    let audio_frames = state.manager.start_streaming()?;
+    let settings = state.manager.get_settings()?;
+
+    let _ = window.emit(
+        "dictation:update",
+        crate::domain::types::DictationUpdate::new(crate::domain::types::DictationState::Streaming),
+    );
+
+    let buffer = std::sync::Arc::new(std::sync::Mutex::new(
+        crate::domain::reconciliation::ReconciliationBuffer::new(settings.streaming_latency_ms, settings.streaming_stability),
+    ));
+    let started_at = std::time::Instant::now();
+
+    let app = window.app_handle().clone();
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<AppState>();
+        let partial_window = app.get_webview_window("main");
+        let partial_app = app.clone();
+        let partial_buffer = buffer.clone();
+
+        let result = state
+            .manager
+            .run_streaming_transcription(
+                &settings,
+                audio_frames,
+                Box::new(move |partial| {
+                    let outcome = partial_buffer.lock().unwrap().reconcile(partial.items, started_at.elapsed());
+
+                    if !outcome.newly_committed_text.is_empty() {
+                        let _ = partial_app
+                            .state::<AppState>()
+                            .manager
+                            .paste_streamed_chunk(&outcome.newly_committed_text);
+                    }
+
+                    if let Some(window) = &partial_window {
+                        let _ = window.emit(
+                            "dictation:update",
+                            crate::domain::types::DictationUpdate::new(crate::domain::types::DictationState::Streaming)
+                                .text(outcome.preview_text),
+                        );
+                    }
+                }),
+            )
+            .await;
+
+        let remainder = buffer.lock().unwrap().flush_remaining();
+        if !remainder.is_empty() {
+            let _ = state.manager.paste_streamed_chunk(&remainder);
+        }
+
+        let finished = state.manager.finish_streaming(result);
+
+        if let Some(window) = app.get_webview_window("main") {
+            let update = match &finished {
+                Ok(text) => {
+                    crate::domain::types::DictationUpdate::new(crate::domain::types::DictationState::Done)
+                        .text(text.clone())
+                }
+                Err(e) => crate::domain::types::DictationUpdate::new(crate::domain::types::DictationState::Error)
+                    .message(e.clone()),
+            };
+            let _ = window.emit("dictation:update", update);
+        }
+
+        if finished.is_ok() {
+            let _ = app.emit("transcription-history-updated", ());
+        }
+    });
+
+    Ok(())
+}
+
+/// Ends streaming capture; the already-running background task (started
+/// by `start_streaming`) commits the final text once the audio channel
+/// closes.
+#[tauri::command]
+pub fn stop_streaming(state: State<'_, AppState>) -> Result<(), String> {
+    state.manager.stop_streaming_capture()
+}
+
 #[tauri::command]
 pub fn get_settings(state: State<'_, AppState>) -> Result<AppSettings, String> {
     state.manager.get_settings()
@@ -74,6 +178,55 @@ pub fn save_settings(settings: AppSettings, state: State<'_, AppState>) -> Resul
     state.manager.save_settings(settings)
 }
 
+#[tauri::command]
+pub fn speak_text(text: String, interrupt: bool, window: WebviewWindow, state: State<'_, AppState>) -> Result<u64, String> {
+    let window = window.clone();
+    state.manager.speak_text(
+        &text,
+        interrupt,
+        Box::new(move |event| {
+            let _ = window.emit("speech:event", event);
+        }),
+    )
+}
+
+#[tauri::command]
+pub fn stop_speaking(state: State<'_, AppState>) -> Result<(), String> {
+    state.manager.stop_speaking()
+}
+
+#[tauri::command]
+pub fn list_voices(state: State<'_, AppState>) -> Result<Vec<VoiceInfo>, String> {
+    Ok(state.manager.list_voices())
+}
+
+/// Grab the chord at the OS level and persist it, only once the grab
+/// succeeds — an already-owned chord returns a recoverable error instead
+/// of silently leaving the old binding (or none) in place.
+#[tauri::command]
+pub fn update_hotkey(
+    app: AppHandle,
+    binding: HotkeyBinding,
+    mode: HotkeyMode,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    crate::hotkey::register(&app, &binding, mode)?;
+
+    let mut settings = state.manager.get_settings()?;
+    settings.hotkey = Some(binding);
+    settings.hotkey_mode = mode;
+    state.manager.save_settings(settings)
+}
+
+#[tauri::command]
+pub fn clear_hotkey(app: AppHandle, state: State<'_, AppState>) -> Result<(), String> {
+    crate::hotkey::unregister(&app);
+
+    let mut settings = state.manager.get_settings()?;
+    settings.hotkey = None;
+    state.manager.save_settings(settings)
+}
+
 #[tauri::command]
 pub fn save_vocabulary(
     vocabulary: Vec<VocabularyEntry>,
@@ -82,6 +235,16 @@ pub fn save_vocabulary(
     state.manager.save_vocabulary(vocabulary)
 }
 
+/// Formats the most recent dictation's timestamped segments as a caption
+/// file, for the frontend to offer as a download.
+#[tauri::command]
+pub fn export_captions(
+    format: crate::captions::CaptionFormat,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    state.manager.export_captions(format)
+}
+
 #[tauri::command]
 pub async fn test_connection(settings: AppSettings) -> Result<String, String> {
     if settings.api_key.trim().is_empty() {
@@ -178,24 +341,111 @@ pub fn hide_settings_window(app: AppHandle) -> Result<(), String> {
 
 /// Background thread that broadcasts audio level events at ~20 FPS while recording.
 /// Both the main window and settings window can subscribe to `audio:level`.
+/// Also drives silence-based auto-stop (VAD) when enabled in `AppSettings`.
 /// Exits when the main window is destroyed (app shutting down).
 pub fn start_audio_level_emitter(app: &AppHandle) {
     let app = app.clone();
     std::thread::spawn(move || {
+        let mut vad = VadTracker::default();
+
         loop {
             std::thread::sleep(std::time::Duration::from_millis(50));
             if app.get_webview_window("main").is_none() {
                 break;
             }
             if !crate::audio::is_recording() {
+                vad.reset();
                 continue;
             }
             let (rms_db, peak_db) = crate::audio::current_level();
             let _ = app.emit("audio:level", AudioLevelPayload { rms_db, peak_db });
+
+            let state = app.state::<AppState>();
+            let Ok(settings) = state.manager.get_settings() else {
+                continue;
+            };
+            if vad.observe(rms_db, &settings.vad) {
+                let window = app.get_webview_window("main");
+                if let Some(window) = &window {
+                    let _ = window.emit(
+                        "dictation:update",
+                        crate::domain::types::DictationUpdate::new(crate::domain::types::DictationState::Recording)
+                            .message("Stopped on silence"),
+                    );
+                }
+
+                let app = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    let state = app.state::<AppState>();
+                    let window = app.get_webview_window("main");
+                    let _ = state
+                        .manager
+                        .stop_and_process(move |update| {
+                            if let Some(window) = &window {
+                                let _ = window.emit("dictation:update", update);
+                            }
+                        })
+                        .await;
+                });
+
+                vad.reset();
+            }
         }
     });
 }
 
+/// Decides, from each RMS reading taken while recording is active, when to
+/// fire silence-based auto-stop; it keeps only the times speech and the
+/// current silence started, not a window of past levels. Speech must first exceed
+/// `onset_threshold_db` (so a quiet start doesn't immediately end the
+/// session), then stay below `silence_threshold_db` for `trailing_silence_ms`
+/// before auto-stop triggers.
+#[derive(Default)]
+struct VadTracker {
+    speech_started_at: Option<std::time::Instant>,
+    silence_started_at: Option<std::time::Instant>,
+}
+
+impl VadTracker {
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns true when auto-stop should fire.
+    fn observe(&mut self, rms_db: f32, vad: &crate::domain::types::VadSettings) -> bool {
+        if !vad.enabled {
+            return false;
+        }
+
+        let now = std::time::Instant::now();
+
+        if rms_db >= vad.onset_threshold_db {
+            if self.speech_started_at.is_none() {
+                self.speech_started_at = Some(now);
+            }
+            self.silence_started_at = None;
+            return false;
+        }
+
+        let Some(speech_started_at) = self.speech_started_at else {
+            // Never heard speech onset yet — a quiet start should not end the session.
+            return false;
+        };
+
+        if now.duration_since(speech_started_at).as_millis() < vad.min_speech_duration_ms as u128 {
+            return false;
+        }
+
+        if rms_db >= vad.silence_threshold_db {
+            self.silence_started_at = None;
+            return false;
+        }
+
+        let silence_started_at = *self.silence_started_at.get_or_insert(now);
+        now.duration_since(silence_started_at).as_millis() >= vad.trailing_silence_ms as u128
+    }
+}
+
 pub fn show_settings_window_internal(app: &AppHandle) -> Result<(), String> {
     let settings_window = app
         .get_webview_window("settings")
@@ -1,7 +1,10 @@
-use crate::{audio::AudioRecorder, clipboard, format_text, settings, transcribe};
+use crate::{
+  audio::AudioRecorder, clipboard, format_text, settings, speech::SpeechEngine, streaming_transcribe, transcribe,
+};
 use crate::transcribe::TranscriptionResult;
 
-use super::ports::{Formatter, Paster, Recorder, SettingsStore, Transcriber};
+use super::ports::{Formatter, Paster, Recorder, SettingsStore, Speaker, StreamingTranscriber, Transcriber};
+use super::types::{SpeechEvent, StreamingPartial, VoiceInfo};
 use crate::settings::AppSettings;
 
 pub struct CpalRecorder(AudioRecorder);
@@ -20,6 +23,10 @@ impl Recorder for CpalRecorder {
   fn stop(&self) -> Result<Vec<u8>, String> {
     self.0.stop()
   }
+
+  fn start_streaming(&self, on_chunk: Box<dyn FnMut(Vec<u8>) + Send>) -> Result<(), String> {
+    self.0.start_chunked(on_chunk)
+  }
 }
 
 pub struct FileAndKeyringSettingsStore;
@@ -37,8 +44,11 @@ impl SettingsStore for FileAndKeyringSettingsStore {
 pub struct ClipboardPaster;
 
 impl Paster for ClipboardPaster {
-  fn paste(&self, text: &str) -> Result<(), String> {
-    clipboard::copy_and_paste(text, true)
+  fn paste(&self, text: &str, settings: &AppSettings) -> Result<(), String> {
+    match settings.paste_mode {
+      super::types::PasteMode::ClipboardPaste => clipboard::copy_and_paste(text, true),
+      super::types::PasteMode::DirectType => clipboard::type_text(text, settings.direct_type_delay_ms),
+    }
   }
 
   fn copy(&self, text: &str) -> Result<(), String> {
@@ -83,3 +93,118 @@ impl Transcriber for OpenAiCompatibleTranscriber {
     .await
   }
 }
+
+pub struct OpenAiCompatibleStreamingTranscriber;
+
+#[async_trait::async_trait]
+impl StreamingTranscriber for OpenAiCompatibleStreamingTranscriber {
+  async fn transcribe_streaming(
+    &self,
+    settings: &AppSettings,
+    audio_frames: tokio::sync::mpsc::Receiver<Vec<u8>>,
+    on_partial: Box<dyn FnMut(StreamingPartial) + Send>,
+  ) -> Result<String, String> {
+    streaming_transcribe::transcribe_streaming(
+      &settings.base_url,
+      &settings.api_key,
+      &settings.model,
+      audio_frames,
+      on_partial,
+    )
+    .await
+  }
+}
+
+/// Fails every streaming request with "Streaming transcription is not
+/// configured". `AppState::production` does not wire it in.
+pub struct NullStreamingTranscriber;
+
+#[async_trait::async_trait]
+impl StreamingTranscriber for NullStreamingTranscriber {
+  async fn transcribe_streaming(
+    &self,
+    _settings: &AppSettings,
+    _audio_frames: tokio::sync::mpsc::Receiver<Vec<u8>>,
+    _on_partial: Box<dyn FnMut(StreamingPartial) + Send>,
+  ) -> Result<String, String> {
+    Err("Streaming transcription is not configured".to_string())
+  }
+}
+
+/// Used when the platform speech engine fails to initialize (e.g. no
+/// SpeechDispatcher on a headless Linux box) so the rest of the app keeps
+/// working without TTS.
+pub struct NullSpeaker;
+
+impl Speaker for NullSpeaker {
+  fn speak(&self, _text: &str, _interrupt: bool, _on_event: Box<dyn FnMut(SpeechEvent) + Send>) -> Result<u64, String> {
+    Err("Speech engine unavailable".to_string())
+  }
+
+  fn stop_speaking(&self) -> Result<(), String> {
+    Ok(())
+  }
+
+  fn list_voices(&self) -> Vec<VoiceInfo> {
+    Vec::new()
+  }
+
+  fn set_rate(&self, _rate: f32) -> Result<(), String> {
+    Err("Speech engine unavailable".to_string())
+  }
+
+  fn set_pitch(&self, _pitch: f32) -> Result<(), String> {
+    Err("Speech engine unavailable".to_string())
+  }
+
+  fn set_volume(&self, _volume: f32) -> Result<(), String> {
+    Err("Speech engine unavailable".to_string())
+  }
+
+  fn set_voice(&self, _voice_id: &str) -> Result<(), String> {
+    Err("Speech engine unavailable".to_string())
+  }
+}
+
+pub struct SystemSpeaker(SpeechEngine);
+
+impl SystemSpeaker {
+  pub fn new() -> Result<Self, String> {
+    Ok(Self(SpeechEngine::new()?))
+  }
+}
+
+impl Speaker for SystemSpeaker {
+  fn speak(
+    &self,
+    text: &str,
+    interrupt: bool,
+    on_event: Box<dyn FnMut(SpeechEvent) + Send>,
+  ) -> Result<u64, String> {
+    self.0.speak(text, interrupt, on_event)
+  }
+
+  fn stop_speaking(&self) -> Result<(), String> {
+    self.0.stop_speaking()
+  }
+
+  fn list_voices(&self) -> Vec<VoiceInfo> {
+    self.0.list_voices()
+  }
+
+  fn set_rate(&self, rate: f32) -> Result<(), String> {
+    self.0.set_rate(rate)
+  }
+
+  fn set_pitch(&self, pitch: f32) -> Result<(), String> {
+    self.0.set_pitch(pitch)
+  }
+
+  fn set_volume(&self, volume: f32) -> Result<(), String> {
+    self.0.set_volume(volume)
+  }
+
+  fn set_voice(&self, voice_id: &str) -> Result<(), String> {
+    self.0.set_voice(voice_id)
+  }
+}
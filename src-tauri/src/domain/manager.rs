@@ -1,10 +1,12 @@
 use std::sync::Mutex;
 
+use crate::captions::CaptionFormat;
 use crate::settings::AppSettings;
+use crate::transcribe::TranscriptionResult;
 
 use super::{
-  ports::{Paster, Recorder, SettingsStore, Transcriber},
-  types::{DictationState, DictationUpdate},
+  ports::{Formatter, Paster, Recorder, Speaker, SettingsStore, StreamingTranscriber, Transcriber},
+  types::{DictationState, DictationUpdate, SpeechEvent, StreamingPartial, VoiceInfo},
 };
 
 pub struct DictationSessionManager {
@@ -14,7 +16,29 @@ pub struct DictationSessionManager {
   recorder: Box<dyn Recorder>,
   settings_store: Box<dyn SettingsStore>,
   transcriber: Box<dyn Transcriber>,
+  streaming_transcriber: Box<dyn StreamingTranscriber>,
   paster: Box<dyn Paster>,
+  speaker: Box<dyn Speaker>,
+  formatter: Box<dyn Formatter>,
+
+  /// Accumulates the text committed so far by the reconciliation buffer
+  /// during a streaming run, so `finish_streaming` can report the whole
+  /// thing without re-pasting what incremental commits already sent.
+  streamed_text: Mutex<String>,
+
+  /// Set while a `transcribe` call is in flight, so `cancel()` can abort
+  /// it. Cleared once the call settles, whether normally or by abort.
+  transcribe_abort: Mutex<Option<futures_util::future::AbortHandle>>,
+  /// Set by `cancel()` immediately before aborting, so `stop_and_process`
+  /// can tell a deliberate cancellation apart from a real transcription
+  /// error and skip emitting a redundant `Error` update after `cancel()`
+  /// has already emitted `Idle`.
+  cancelled: Mutex<bool>,
+
+  /// The most recent successful transcription, including its timestamped
+  /// segments, kept around so `export_captions` can format them on
+  /// request without re-transcribing.
+  last_result: Mutex<Option<TranscriptionResult>>,
 }
 
 impl DictationSessionManager {
@@ -22,7 +46,10 @@ impl DictationSessionManager {
     recorder: Box<dyn Recorder>,
     settings_store: Box<dyn SettingsStore>,
     transcriber: Box<dyn Transcriber>,
+    streaming_transcriber: Box<dyn StreamingTranscriber>,
     paster: Box<dyn Paster>,
+    speaker: Box<dyn Speaker>,
+    formatter: Box<dyn Formatter>,
   ) -> Self {
     let initial_settings = settings_store.load();
     Self {
@@ -31,10 +58,38 @@ impl DictationSessionManager {
       recorder,
       settings_store,
       transcriber,
+      streaming_transcriber,
       paster,
+      speaker,
+      formatter,
+      streamed_text: Mutex::new(String::new()),
+      transcribe_abort: Mutex::new(None),
+      cancelled: Mutex::new(false),
+      last_result: Mutex::new(None),
     }
   }
 
+  pub fn speak_text(
+    &self,
+    text: &str,
+    interrupt: bool,
+    on_event: Box<dyn FnMut(SpeechEvent) + Send>,
+  ) -> Result<u64, String> {
+    self.speaker.speak(text, interrupt, on_event)
+  }
+
+  pub fn stop_speaking(&self) -> Result<(), String> {
+    self.speaker.stop_speaking()
+  }
+
+  pub fn list_voices(&self) -> Vec<VoiceInfo> {
+    self.speaker.list_voices()
+  }
+
+  pub fn is_recording(&self) -> bool {
+    matches!(self.state.lock().as_deref(), Ok(&DictationState::Recording))
+  }
+
   pub fn get_settings(&self) -> Result<AppSettings, String> {
     Ok(
       self
@@ -47,6 +102,16 @@ impl DictationSessionManager {
 
   pub fn save_settings(&self, settings: AppSettings) -> Result<(), String> {
     self.settings_store.save(&settings)?;
+
+    // Best-effort: a backend without an active voice installed shouldn't
+    // block saving the rest of the settings.
+    let _ = self.speaker.set_rate(settings.tts_rate);
+    let _ = self.speaker.set_pitch(settings.tts_pitch);
+    let _ = self.speaker.set_volume(settings.tts_volume);
+    if let Some(voice_id) = &settings.tts_voice_id {
+      let _ = self.speaker.set_voice(voice_id);
+    }
+
     let mut guard = self
       .settings
       .lock()
@@ -102,14 +167,58 @@ impl DictationSessionManager {
         .map_err(|_| "Settings lock poisoned".to_string())?
         .clone();
 
-      let text = self.transcriber.transcribe(&settings, wav_data).await?;
+      let (transcribe_fut, abort_handle) = futures_util::future::abortable(self.transcriber.transcribe(&settings, wav_data, None));
+
+      {
+        let mut guard = self
+          .transcribe_abort
+          .lock()
+          .map_err(|_| "Abort handle lock poisoned".to_string())?;
+        *guard = Some(abort_handle);
+      }
+
+      let transcribe_result = transcribe_fut.await;
+
+      {
+        let mut guard = self
+          .transcribe_abort
+          .lock()
+          .map_err(|_| "Abort handle lock poisoned".to_string())?;
+        *guard = None;
+      }
+
+      let text = match transcribe_result {
+        Ok(result) => {
+          let result = result?;
+          let text = result.text.clone();
+          *self
+            .last_result
+            .lock()
+            .map_err(|_| "Last result lock poisoned".to_string())? = Some(result);
+          text
+        }
+        Err(_aborted) => return Err("Transcription cancelled".to_string()),
+      };
+      let text = crate::vocabulary_filter::apply_vocabulary_filter(&text, &settings.vocabulary_filter);
+
+      // Best-effort: if the formatter call fails, paste the unformatted
+      // transcription rather than losing the dictation entirely.
+      let text = if settings.format_enabled && !settings.format_system_prompt.trim().is_empty() {
+        self
+          .formatter
+          .format(&settings.base_url, &settings.api_key, &settings.model, &settings.format_system_prompt, &text)
+          .await
+          .unwrap_or(text)
+      } else {
+        text
+      };
 
       {
         let _ = self.set_state(DictationState::Pasting);
       }
       on_update(DictationUpdate::new(DictationState::Pasting));
 
-      self.paster.paste(&text)?;
+      self.paster.paste(&text, &settings)?;
 
       {
         let _ = self.set_state(DictationState::Done);
@@ -126,12 +235,169 @@ impl DictationSessionManager {
     match result {
       Ok(text) => Ok(text),
       Err(err) => {
-        on_update(DictationUpdate::new(DictationState::Error).message(err.clone()));
+        let was_cancelled = self
+          .cancelled
+          .lock()
+          .map(|mut cancelled| std::mem::take(&mut *cancelled))
+          .unwrap_or(false);
+
+        // `cancel()` already emitted its own `Idle` update; don't also
+        // report the abort it caused as a transcription error.
+        if !was_cancelled {
+          on_update(DictationUpdate::new(DictationState::Error).message(err.clone()));
+        }
         Err(err)
       }
     }
   }
 
+  /// Aborts an in-flight `stop_and_process` transcription, returning the
+  /// state machine to `Idle` and dropping any partial result without
+  /// pasting it. Returns an error if there's nothing in flight to cancel.
+  pub fn cancel<F>(&self, mut on_update: F) -> Result<(), String>
+  where
+    F: FnMut(DictationUpdate),
+  {
+    let handle = self
+      .transcribe_abort
+      .lock()
+      .map_err(|_| "Abort handle lock poisoned".to_string())?
+      .take();
+
+    let Some(handle) = handle else {
+      return Err("No in-flight transcription to cancel".to_string());
+    };
+
+    *self
+      .cancelled
+      .lock()
+      .map_err(|_| "Cancelled flag lock poisoned".to_string())? = true;
+
+    handle.abort();
+    let _ = self.set_state(DictationState::Idle);
+    on_update(DictationUpdate::new(DictationState::Idle));
+    Ok(())
+  }
+
+  /// Formats the most recent transcription's timestamped segments as a
+  /// caption file in the requested format.
+  pub fn export_captions(&self, format: CaptionFormat) -> Result<String, String> {
+    let last_result = self
+      .last_result
+      .lock()
+      .map_err(|_| "Last result lock poisoned".to_string())?;
+
+    let result = last_result
+      .as_ref()
+      .ok_or_else(|| "No transcription available to export captions from".to_string())?;
+
+    let segments = result
+      .segments
+      .as_deref()
+      .filter(|segments| !segments.is_empty())
+      .ok_or_else(|| "This transcription has no timestamped segments to export".to_string())?;
+
+    Ok(match format {
+      CaptionFormat::Srt => crate::captions::to_srt(segments, result.duration_secs),
+      CaptionFormat::WebVtt => crate::captions::to_webvtt(segments, result.duration_secs),
+    })
+  }
+
+  /// Begin streaming capture: transitions to `Streaming` and starts
+  /// pushing captured audio chunks into the returned channel. The caller
+  /// (which owns the `AppHandle`/tauri event loop) is expected to hand the
+  /// receiver to `run_streaming_transcription` on a background task.
+  pub fn start_streaming(&self) -> Result<tokio::sync::mpsc::Receiver<Vec<u8>>, String> {
+    {
+      let mut state = self.state.lock().map_err(|_| "State lock poisoned".to_string())?;
+      if *state != DictationState::Idle {
+        return Err("Busy".to_string());
+      }
+      *state = DictationState::Streaming;
+    }
+
+    *self
+      .streamed_text
+      .lock()
+      .map_err(|_| "Streamed text lock poisoned".to_string())? = String::new();
+
+    let (tx, rx) = tokio::sync::mpsc::channel(64);
+
+    if let Err(e) = self.recorder.start_streaming(Box::new(move |chunk| {
+      let _ = tx.blocking_send(chunk);
+    })) {
+      let _ = self.set_state(DictationState::Idle);
+      return Err(e);
+    }
+
+    Ok(rx)
+  }
+
+  /// Drives the streaming provider for the lifetime of the `audio_frames`
+  /// channel, resolving to the final committed text once recording stops
+  /// and the channel closes.
+  pub async fn run_streaming_transcription(
+    &self,
+    settings: &AppSettings,
+    audio_frames: tokio::sync::mpsc::Receiver<Vec<u8>>,
+    on_partial: Box<dyn FnMut(StreamingPartial) + Send>,
+  ) -> Result<String, String> {
+    self
+      .streaming_transcriber
+      .transcribe_streaming(settings, audio_frames, on_partial)
+      .await
+  }
+
+  /// Tears down the streaming capture started by `start_streaming`. Ends
+  /// the audio channel (closing the sender owned by the recorder's capture
+  /// thread), which lets `run_streaming_transcription` finalize.
+  pub fn stop_streaming_capture(&self) -> Result<(), String> {
+    self.recorder.stop().map(|_| ())
+  }
+
+  /// Pastes one chunk of text newly committed by the reconciliation
+  /// buffer and appends it to the running total for this streaming
+  /// session, so the committed transcript is built up incrementally
+  /// instead of all at once when the session ends.
+  pub fn paste_streamed_chunk(&self, chunk: &str) -> Result<(), String> {
+    if chunk.is_empty() {
+      return Ok(());
+    }
+
+    let settings = self.get_settings()?;
+    self.paster.paste(chunk, &settings)?;
+
+    let mut streamed_text = self
+      .streamed_text
+      .lock()
+      .map_err(|_| "Streamed text lock poisoned".to_string())?;
+    if !streamed_text.is_empty() {
+      streamed_text.push(' ');
+    }
+    streamed_text.push_str(chunk);
+    Ok(())
+  }
+
+  /// Finishes a streaming run: the committed transcript has already been
+  /// pasted incrementally via `paste_streamed_chunk`, so this only settles
+  /// the final state and reports the accumulated text (or the error),
+  /// then always returns to `Idle`.
+  pub fn finish_streaming(&self, result: Result<String, String>) -> Result<String, String> {
+    let outcome = (|| {
+      result?;
+      let text = self
+        .streamed_text
+        .lock()
+        .map_err(|_| "Streamed text lock poisoned".to_string())?
+        .clone();
+      let _ = self.set_state(DictationState::Done);
+      Ok(text)
+    })();
+
+    let _ = self.set_state(DictationState::Idle);
+    outcome
+  }
+
   fn set_state(&self, next: DictationState) -> Result<(), String> {
     let mut state = self.state.lock().map_err(|_| "State lock poisoned".to_string())?;
     *state = next;
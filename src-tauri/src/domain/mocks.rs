@@ -0,0 +1,258 @@
+use std::sync::Mutex;
+
+use crate::settings::AppSettings;
+use crate::transcribe::TranscriptionResult;
+
+use super::ports::{Formatter, Paster, Recorder, SettingsStore, Speaker, StreamingTranscriber, Transcriber};
+use super::types::{SpeechEvent, StreamingPartial, VoiceInfo};
+
+/// Returns canned WAV bytes on `stop()` instead of touching CPAL, so the
+/// dictation flow can be driven in tests without real audio hardware.
+pub struct MockRecorder {
+  pub wav_bytes: Vec<u8>,
+}
+
+impl MockRecorder {
+  pub fn new(wav_bytes: Vec<u8>) -> Self {
+    Self { wav_bytes }
+  }
+}
+
+impl Recorder for MockRecorder {
+  fn start(&self) -> Result<(), String> {
+    Ok(())
+  }
+
+  fn stop(&self) -> Result<Vec<u8>, String> {
+    Ok(self.wav_bytes.clone())
+  }
+}
+
+/// Returns a scripted `TranscriptionResult` instead of hitting the network.
+pub struct MockTranscriber {
+  pub result: Mutex<Result<TranscriptionResult, String>>,
+}
+
+impl MockTranscriber {
+  pub fn new(result: Result<TranscriptionResult, String>) -> Self {
+    Self {
+      result: Mutex::new(result),
+    }
+  }
+
+  pub fn with_text(text: impl Into<String>) -> Self {
+    Self::new(Ok(TranscriptionResult {
+      text: text.into(),
+      duration_secs: None,
+      language: None,
+      segments: None,
+    }))
+  }
+}
+
+#[async_trait::async_trait]
+impl Transcriber for MockTranscriber {
+  async fn transcribe(
+    &self,
+    _settings: &AppSettings,
+    _audio_wav: Vec<u8>,
+    _prompt: Option<&str>,
+  ) -> Result<TranscriptionResult, String> {
+    self
+      .result
+      .lock()
+      .map_err(|_| "Mock transcriber lock poisoned".to_string())?
+      .clone()
+  }
+}
+
+/// Feeds back a scripted sequence of partials, then resolves with the
+/// last one's text, ignoring whatever audio actually arrives.
+pub struct MockStreamingTranscriber {
+  pub partials: Vec<StreamingPartial>,
+}
+
+impl MockStreamingTranscriber {
+  pub fn new(partials: Vec<StreamingPartial>) -> Self {
+    Self { partials }
+  }
+}
+
+#[async_trait::async_trait]
+impl StreamingTranscriber for MockStreamingTranscriber {
+  async fn transcribe_streaming(
+    &self,
+    _settings: &AppSettings,
+    mut audio_frames: tokio::sync::mpsc::Receiver<Vec<u8>>,
+    mut on_partial: Box<dyn FnMut(StreamingPartial) + Send>,
+  ) -> Result<String, String> {
+    for partial in &self.partials {
+      on_partial(partial.clone());
+    }
+    // Drain until the capture side closes the channel (recording stopped).
+    while audio_frames.recv().await.is_some() {}
+    Ok(self
+      .partials
+      .last()
+      .map(|p| p.items.iter().map(|item| item.text.as_str()).collect::<Vec<_>>().join(" "))
+      .unwrap_or_default())
+  }
+}
+
+/// Echoes the input text back, unmodified.
+pub struct MockFormatter;
+
+#[async_trait::async_trait]
+impl Formatter for MockFormatter {
+  async fn format(
+    &self,
+    _base_url: &str,
+    _api_key: &str,
+    _model: &str,
+    _system_prompt: &str,
+    text: &str,
+  ) -> Result<String, String> {
+    Ok(text.to_string())
+  }
+}
+
+/// Records what would have been pasted/copied instead of touching the OS
+/// clipboard or simulating keystrokes.
+#[derive(Default)]
+pub struct CapturingPaster {
+  pub pasted: Mutex<Vec<String>>,
+  pub copied: Mutex<Vec<String>>,
+}
+
+impl CapturingPaster {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn last_pasted(&self) -> Option<String> {
+    self.pasted.lock().ok()?.last().cloned()
+  }
+}
+
+impl Paster for std::sync::Arc<CapturingPaster> {
+  fn paste(&self, text: &str, settings: &AppSettings) -> Result<(), String> {
+    CapturingPaster::paste(self, text, settings)
+  }
+
+  fn copy(&self, text: &str) -> Result<(), String> {
+    CapturingPaster::copy(self, text)
+  }
+}
+
+impl Paster for CapturingPaster {
+  fn paste(&self, text: &str, _settings: &AppSettings) -> Result<(), String> {
+    self
+      .pasted
+      .lock()
+      .map_err(|_| "Capturing paster lock poisoned".to_string())?
+      .push(text.to_string());
+    Ok(())
+  }
+
+  fn copy(&self, text: &str) -> Result<(), String> {
+    self
+      .copied
+      .lock()
+      .map_err(|_| "Capturing paster lock poisoned".to_string())?
+      .push(text.to_string());
+    Ok(())
+  }
+}
+
+/// Keeps settings in memory instead of reading/writing the settings file.
+pub struct InMemorySettingsStore {
+  pub settings: Mutex<AppSettings>,
+}
+
+impl InMemorySettingsStore {
+  pub fn new(settings: AppSettings) -> Self {
+    Self {
+      settings: Mutex::new(settings),
+    }
+  }
+}
+
+impl Default for InMemorySettingsStore {
+  fn default() -> Self {
+    Self::new(AppSettings::default())
+  }
+}
+
+impl SettingsStore for InMemorySettingsStore {
+  fn load(&self) -> AppSettings {
+    self.settings.lock().map(|s| s.clone()).unwrap_or_default()
+  }
+
+  fn save(&self, settings: &AppSettings) -> Result<(), String> {
+    *self
+      .settings
+      .lock()
+      .map_err(|_| "In-memory settings store lock poisoned".to_string())? = settings.clone();
+    Ok(())
+  }
+}
+
+/// Never actually speaks; records every utterance and reports events
+/// synchronously so speech-driven tests don't need a real TTS backend.
+#[derive(Default)]
+pub struct MockSpeaker {
+  pub spoken: Mutex<Vec<String>>,
+}
+
+impl Speaker for MockSpeaker {
+  fn speak(&self, text: &str, _interrupt: bool, mut on_event: Box<dyn FnMut(SpeechEvent) + Send>) -> Result<u64, String> {
+    let utterance_id = self
+      .spoken
+      .lock()
+      .map_err(|_| "Mock speaker lock poisoned".to_string())?
+      .len() as u64
+      + 1;
+    self
+      .spoken
+      .lock()
+      .map_err(|_| "Mock speaker lock poisoned".to_string())?
+      .push(text.to_string());
+    on_event(SpeechEvent {
+      utterance_id,
+      phase: super::types::SpeechPhase::Begin,
+    });
+    on_event(SpeechEvent {
+      utterance_id,
+      phase: super::types::SpeechPhase::End,
+    });
+    Ok(utterance_id)
+  }
+
+  fn stop_speaking(&self) -> Result<(), String> {
+    Ok(())
+  }
+
+  fn list_voices(&self) -> Vec<VoiceInfo> {
+    vec![VoiceInfo {
+      id: "mock".to_string(),
+      language: "en-US".to_string(),
+      name: "Mock Voice".to_string(),
+    }]
+  }
+
+  fn set_rate(&self, _rate: f32) -> Result<(), String> {
+    Ok(())
+  }
+
+  fn set_pitch(&self, _pitch: f32) -> Result<(), String> {
+    Ok(())
+  }
+
+  fn set_volume(&self, _volume: f32) -> Result<(), String> {
+    Ok(())
+  }
+
+  fn set_voice(&self, _voice_id: &str) -> Result<(), String> {
+    Ok(())
+  }
+}
@@ -0,0 +1,7 @@
+pub mod impls;
+pub mod manager;
+#[cfg(test)]
+pub mod mocks;
+pub mod ports;
+pub mod reconciliation;
+pub mod types;
@@ -0,0 +1,83 @@
+use crate::settings::AppSettings;
+use crate::transcribe::TranscriptionResult;
+
+use super::types::{SpeechEvent, StreamingPartial, VoiceInfo};
+
+pub trait Recorder: Send + Sync {
+  fn start(&self) -> Result<(), String>;
+  fn stop(&self) -> Result<Vec<u8>, String>;
+
+  /// Start capturing and push raw audio chunks to `on_chunk` as they
+  /// arrive, for streaming transcription. `stop` tears the capture down
+  /// the same way it does for the buffered path. Default: unsupported.
+  fn start_streaming(&self, _on_chunk: Box<dyn FnMut(Vec<u8>) + Send>) -> Result<(), String> {
+    Err("Streaming capture is not supported by this recorder".to_string())
+  }
+}
+
+#[async_trait::async_trait]
+pub trait Transcriber: Send + Sync {
+  async fn transcribe(
+    &self,
+    settings: &AppSettings,
+    audio_wav: Vec<u8>,
+    prompt: Option<&str>,
+  ) -> Result<TranscriptionResult, String>;
+}
+
+#[async_trait::async_trait]
+pub trait Formatter: Send + Sync {
+  async fn format(
+    &self,
+    base_url: &str,
+    api_key: &str,
+    model: &str,
+    system_prompt: &str,
+    text: &str,
+  ) -> Result<String, String>;
+}
+
+pub trait Paster: Send + Sync {
+  fn paste(&self, text: &str, settings: &AppSettings) -> Result<(), String>;
+  fn copy(&self, text: &str) -> Result<(), String>;
+}
+
+pub trait SettingsStore: Send + Sync {
+  fn load(&self) -> AppSettings;
+  fn save(&self, settings: &AppSettings) -> Result<(), String>;
+}
+
+/// Real-time streaming transcription: pushes audio as it is captured over
+/// a persistent connection and receives incremental hypotheses via
+/// `on_partial`, resolving to the final committed text once `audio_frames`
+/// closes (recording stopped).
+#[async_trait::async_trait]
+pub trait StreamingTranscriber: Send + Sync {
+  async fn transcribe_streaming(
+    &self,
+    settings: &AppSettings,
+    audio_frames: tokio::sync::mpsc::Receiver<Vec<u8>>,
+    on_partial: Box<dyn FnMut(StreamingPartial) + Send>,
+  ) -> Result<String, String>;
+}
+
+/// Text-to-speech read-back. `speak` returns a monotonically-increasing
+/// utterance id and drives `on_event` with begin/end notifications so the
+/// UI can highlight what is currently being spoken.
+pub trait Speaker: Send + Sync {
+  fn speak(
+    &self,
+    text: &str,
+    interrupt: bool,
+    on_event: Box<dyn FnMut(SpeechEvent) + Send>,
+  ) -> Result<u64, String>;
+
+  fn stop_speaking(&self) -> Result<(), String>;
+
+  fn list_voices(&self) -> Vec<VoiceInfo>;
+
+  fn set_rate(&self, rate: f32) -> Result<(), String>;
+  fn set_pitch(&self, pitch: f32) -> Result<(), String>;
+  fn set_volume(&self, volume: f32) -> Result<(), String>;
+  fn set_voice(&self, voice_id: &str) -> Result<(), String>;
+}
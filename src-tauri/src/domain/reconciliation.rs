@@ -0,0 +1,205 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use super::types::{StabilityLevel, StreamingItem};
+
+/// What changed after folding a new partial into the buffer.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ReconcileOutcome {
+  /// Text newly committed by this update; empty if nothing became stable.
+  pub newly_committed_text: String,
+  /// The full uncommitted tail, for preview display only — never pasted.
+  pub preview_text: String,
+}
+
+/// Reconciles a stream of overlapping, possibly out-of-order partial
+/// hypotheses from a streaming transcription provider into a stable,
+/// monotonically-growing transcript. Modeled on the AWS Transcribe
+/// result-stability design: items are committed once the provider marks
+/// them `stable` or once they age past a configurable `latency`, and
+/// committed items are never rewritten by a later partial.
+pub struct ReconciliationBuffer {
+  items: VecDeque<StreamingItem>,
+  committed: usize,
+  latency: Duration,
+}
+
+impl ReconciliationBuffer {
+  pub fn new(latency_ms: u64, stability: StabilityLevel) -> Self {
+    Self {
+      items: VecDeque::new(),
+      committed: 0,
+      latency: Duration::from_millis(stability.scale_latency_ms(latency_ms)),
+    }
+  }
+
+  /// Folds a new partial's items into the buffer and commits whichever of
+  /// them are now stable or aged out. `elapsed` is the time since
+  /// streaming started, used to age out items by `end_time`.
+  pub fn reconcile(&mut self, items: Vec<StreamingItem>, elapsed: Duration) -> ReconcileOutcome {
+    self.merge(items);
+    self.commit_ready(elapsed)
+  }
+
+  /// Forces every remaining uncommitted item to commit. Call once the
+  /// provider's stream has ended, since no further corrections can arrive.
+  pub fn flush_remaining(&mut self) -> String {
+    let tail: Vec<String> = self.items.iter().skip(self.committed).map(|item| item.text.clone()).collect();
+    self.committed = self.items.len();
+    join(&tail)
+  }
+
+  fn merge(&mut self, mut incoming: Vec<StreamingItem>) {
+    incoming.sort_by(|a, b| a.start_time.partial_cmp(&b.start_time).unwrap_or(std::cmp::Ordering::Equal));
+
+    // A provider resending a corrected hypothesis for the same slot shows
+    // up as two items sharing a start_time; keep the latest.
+    let mut deduped: Vec<StreamingItem> = Vec::with_capacity(incoming.len());
+    for item in incoming {
+      match deduped.last_mut() {
+        Some(last) if same_slot(last, &item) => *last = item,
+        _ => deduped.push(item),
+      }
+    }
+
+    // Already-committed items are never touched, so drop any re-sent
+    // items that fall before the commit boundary before comparing.
+    let committed_boundary = self.items.get(self.committed.wrapping_sub(1)).map(|i| i.end_time);
+    let deduped: Vec<StreamingItem> = deduped
+      .into_iter()
+      .filter(|item| committed_boundary.map_or(true, |boundary| item.start_time >= boundary))
+      .collect();
+
+    let tail: Vec<StreamingItem> = self.items.iter().skip(self.committed).cloned().collect();
+
+    let divergence = tail
+      .iter()
+      .zip(deduped.iter())
+      .position(|(existing, new)| !same_slot(existing, new) || existing.text != new.text)
+      .unwrap_or_else(|| tail.len().min(deduped.len()));
+
+    self.items.truncate(self.committed + divergence);
+    self.items.extend(deduped.into_iter().skip(divergence));
+  }
+
+  fn commit_ready(&mut self, elapsed: Duration) -> ReconcileOutcome {
+    let mut newly_committed = Vec::new();
+
+    while self.committed < self.items.len() {
+      let item = &self.items[self.committed];
+      let aged_out = elapsed.as_secs_f64() - item.end_time >= self.latency.as_secs_f64();
+
+      if !item.stable && !aged_out {
+        break;
+      }
+
+      newly_committed.push(item.text.clone());
+      self.committed += 1;
+    }
+
+    let preview: Vec<String> = self.items.iter().skip(self.committed).map(|item| item.text.clone()).collect();
+
+    ReconcileOutcome {
+      newly_committed_text: join(&newly_committed),
+      preview_text: join(&preview),
+    }
+  }
+}
+
+fn same_slot(a: &StreamingItem, b: &StreamingItem) -> bool {
+  (a.start_time - b.start_time).abs() < f64::EPSILON
+}
+
+fn join(words: &[String]) -> String {
+  words.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn item(start: f64, end: f64, text: &str, stable: bool) -> StreamingItem {
+    StreamingItem {
+      start_time: start,
+      end_time: end,
+      text: text.to_string(),
+      stable,
+    }
+  }
+
+  #[test]
+  fn commits_items_flagged_stable_by_the_server() {
+    let mut buffer = ReconciliationBuffer::new(2_000, StabilityLevel::Medium);
+
+    let outcome = buffer.reconcile(
+      vec![item(0.0, 0.5, "hello", true), item(0.5, 1.0, "world", false)],
+      Duration::from_secs(1),
+    );
+
+    assert_eq!(outcome.newly_committed_text, "hello");
+    assert_eq!(outcome.preview_text, "world");
+  }
+
+  #[test]
+  fn commits_items_once_they_age_past_latency() {
+    let mut buffer = ReconciliationBuffer::new(2_000, StabilityLevel::Medium);
+    buffer.reconcile(vec![item(0.0, 0.5, "hello", false)], Duration::from_millis(600));
+
+    let outcome = buffer.reconcile(vec![item(0.0, 0.5, "hello", false)], Duration::from_millis(3_000));
+
+    assert_eq!(outcome.newly_committed_text, "hello");
+  }
+
+  #[test]
+  fn replaces_uncommitted_tail_at_the_divergence_point_without_touching_committed_items() {
+    let mut buffer = ReconciliationBuffer::new(2_000, StabilityLevel::Medium);
+    buffer.reconcile(
+      vec![item(0.0, 0.5, "hello", true), item(0.5, 1.0, "wurld", false)],
+      Duration::from_secs(1),
+    );
+
+    let outcome = buffer.reconcile(
+      vec![item(0.0, 0.5, "hello", true), item(0.5, 1.0, "world", false), item(1.0, 1.5, "there", false)],
+      Duration::from_secs(1),
+    );
+
+    assert_eq!(outcome.newly_committed_text, "");
+    assert_eq!(outcome.preview_text, "world there");
+  }
+
+  #[test]
+  fn dedups_duplicate_items_sharing_a_start_time() {
+    let mut buffer = ReconciliationBuffer::new(2_000, StabilityLevel::Medium);
+
+    let outcome = buffer.reconcile(
+      vec![item(0.0, 0.5, "hell", false), item(0.0, 0.6, "hello", false)],
+      Duration::from_millis(100),
+    );
+
+    assert_eq!(outcome.preview_text, "hello");
+  }
+
+  #[test]
+  fn high_stability_commits_more_aggressively_than_low() {
+    let mut low = ReconciliationBuffer::new(2_000, StabilityLevel::Low);
+    let mut high = ReconciliationBuffer::new(2_000, StabilityLevel::High);
+
+    let low_outcome = low.reconcile(vec![item(0.0, 0.5, "hello", false)], Duration::from_millis(1_500));
+    let high_outcome = high.reconcile(vec![item(0.0, 0.5, "hello", false)], Duration::from_millis(1_500));
+
+    assert_eq!(low_outcome.newly_committed_text, "");
+    assert_eq!(high_outcome.newly_committed_text, "hello");
+  }
+
+  #[test]
+  fn flush_remaining_commits_everything_still_uncommitted() {
+    let mut buffer = ReconciliationBuffer::new(2_000, StabilityLevel::Medium);
+    buffer.reconcile(
+      vec![item(0.0, 0.5, "hello", false), item(0.5, 1.0, "world", false)],
+      Duration::from_millis(100),
+    );
+
+    assert_eq!(buffer.flush_remaining(), "hello world");
+    assert_eq!(buffer.flush_remaining(), "");
+  }
+}
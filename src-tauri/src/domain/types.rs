@@ -9,6 +9,7 @@ fn default_true() -> bool {
 pub enum DictationState {
   Idle,
   Recording,
+  Streaming,
   Transcribing,
   Pasting,
   Done,
@@ -55,3 +56,199 @@ pub struct VocabularyEntry {
   #[serde(default = "default_true")]
   pub enabled: bool,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoiceInfo {
+  pub id: String,
+  pub language: String,
+  pub name: String,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpeechPhase {
+  Begin,
+  End,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpeechEvent {
+  pub utterance_id: u64,
+  pub phase: SpeechPhase,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HotkeyMode {
+  PushToTalk,
+  Toggle,
+}
+
+impl Default for HotkeyMode {
+  fn default() -> Self {
+    HotkeyMode::Toggle
+  }
+}
+
+/// A chord of modifier keys plus a single key, e.g. `{ modifiers: ["ctrl", "alt"], key: "Space" }`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct HotkeyBinding {
+  #[serde(default)]
+  pub modifiers: Vec<String>,
+  #[serde(default)]
+  pub key: String,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PasteMode {
+  ClipboardPaste,
+  DirectType,
+}
+
+impl Default for PasteMode {
+  fn default() -> Self {
+    PasteMode::ClipboardPaste
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VadSettings {
+  #[serde(default)]
+  pub enabled: bool,
+  #[serde(default = "VadSettings::default_onset_threshold_db")]
+  pub onset_threshold_db: f32,
+  #[serde(default = "VadSettings::default_silence_threshold_db")]
+  pub silence_threshold_db: f32,
+  #[serde(default = "VadSettings::default_min_speech_duration_ms")]
+  pub min_speech_duration_ms: u64,
+  #[serde(default = "VadSettings::default_trailing_silence_ms")]
+  pub trailing_silence_ms: u64,
+}
+
+impl VadSettings {
+  fn default_onset_threshold_db() -> f32 {
+    -40.0
+  }
+
+  fn default_silence_threshold_db() -> f32 {
+    -50.0
+  }
+
+  fn default_min_speech_duration_ms() -> u64 {
+    300
+  }
+
+  fn default_trailing_silence_ms() -> u64 {
+    1_200
+  }
+}
+
+/// A single word/phrase entry from a streaming transcription provider,
+/// modeled on the AWS Transcribe streaming item shape: a time range, the
+/// recognized text, and whether the provider itself considers it stable
+/// (won't be revised by a later partial).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StreamingItem {
+  pub start_time: f64,
+  pub end_time: f64,
+  pub text: String,
+  pub stable: bool,
+}
+
+/// How a matched vocabulary filter entry is rewritten before pasting,
+/// naming matched after the AWS Transcribe `VocabularyFilterMethod`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VocabularyFilterMethod {
+  Mask,
+  Remove,
+  Tag,
+}
+
+impl Default for VocabularyFilterMethod {
+  fn default() -> Self {
+    VocabularyFilterMethod::Mask
+  }
+}
+
+/// A list of words/phrases to scrub from the transcribed text before it's
+/// pasted, independent of whatever vocabulary hints were sent to the
+/// transcription provider.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VocabularyFilterSettings {
+  #[serde(default)]
+  pub enabled: bool,
+  #[serde(default)]
+  pub method: VocabularyFilterMethod,
+  #[serde(default)]
+  pub words: Vec<String>,
+  #[serde(default = "VocabularyFilterSettings::default_tag_marker")]
+  pub tag_marker: String,
+}
+
+impl VocabularyFilterSettings {
+  fn default_tag_marker() -> String {
+    "[{}]".to_string()
+  }
+}
+
+impl Default for VocabularyFilterSettings {
+  fn default() -> Self {
+    Self {
+      enabled: false,
+      method: VocabularyFilterMethod::default(),
+      words: Vec::new(),
+      tag_marker: Self::default_tag_marker(),
+    }
+  }
+}
+
+/// One incremental hypothesis from a streaming transcription provider.
+/// `is_final` marks the provider's own end-of-utterance signal, independent
+/// of the reconciliation buffer's own stability tracking.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreamingPartial {
+  pub items: Vec<StreamingItem>,
+  pub is_final: bool,
+}
+
+/// How aggressively the reconciliation buffer commits items that the
+/// provider hasn't flagged `stable` yet, by scaling the configured base
+/// latency: `Low` waits longer for extra confidence, `High` commits sooner
+/// at the risk of more later corrections.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StabilityLevel {
+  Low,
+  Medium,
+  High,
+}
+
+impl Default for StabilityLevel {
+  fn default() -> Self {
+    StabilityLevel::Medium
+  }
+}
+
+impl StabilityLevel {
+  pub fn scale_latency_ms(self, base_ms: u64) -> u64 {
+    match self {
+      StabilityLevel::Low => base_ms.saturating_mul(3) / 2,
+      StabilityLevel::Medium => base_ms,
+      StabilityLevel::High => base_ms / 2,
+    }
+  }
+}
+
+impl Default for VadSettings {
+  fn default() -> Self {
+    Self {
+      enabled: false,
+      onset_threshold_db: Self::default_onset_threshold_db(),
+      silence_threshold_db: Self::default_silence_threshold_db(),
+      min_speech_duration_ms: Self::default_min_speech_duration_ms(),
+      trailing_silence_ms: Self::default_trailing_silence_ms(),
+    }
+  }
+}
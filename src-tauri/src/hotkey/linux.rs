@@ -0,0 +1,183 @@
+use std::ffi::CString;
+use std::os::raw::c_ulong;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, Once};
+
+use once_cell::sync::Lazy;
+use tauri::AppHandle;
+use x11::xlib;
+
+use crate::domain::types::{HotkeyBinding, HotkeyMode};
+
+static GRABBED: AtomicBool = AtomicBool::new(false);
+static WATCHER: Lazy<Mutex<Option<std::thread::JoinHandle<()>>>> = Lazy::new(|| Mutex::new(None));
+/// The watcher thread's `(Display*, wake window)`, stashed so `unregister`
+/// can wake the thread out of its blocking `XNextEvent` call. Stored as a
+/// raw `usize` so it can live in a `Mutex` without fighting `Send`.
+static WAKE_TARGET: Mutex<Option<(usize, c_ulong)>> = Mutex::new(None);
+static INIT_THREADS: Once = Once::new();
+
+/// X11 key grab via `XGrabKey`. Wayland compositors that support the
+/// GlobalShortcuts portal are expected to route through the same entry
+/// point once the portal backend lands; X11 is the baseline today.
+pub fn register(app: &AppHandle, binding: &HotkeyBinding, mode: HotkeyMode) -> Result<(), String> {
+  let (modifiers, keysym) = parse_binding(binding)?;
+
+  // `unregister` wakes the watcher thread by sending it an event over the
+  // same Display connection from a different thread, which Xlib only
+  // supports once threading has been initialized.
+  INIT_THREADS.call_once(|| unsafe {
+    xlib::XInitThreads();
+  });
+
+  unsafe {
+    let display = xlib::XOpenDisplay(std::ptr::null());
+    if display.is_null() {
+      return Err("Could not open X11 display for global hotkey".to_string());
+    }
+
+    let root = xlib::XDefaultRootWindow(display);
+    let keycode = xlib::XKeysymToKeycode(display, keysym as u64);
+    if keycode == 0 {
+      xlib::XCloseDisplay(display);
+      return Err(format!("Unknown key '{}' for global hotkey", binding.key));
+    }
+
+    // Grab the combination plus the common lock-key variants, since X11
+    // treats Num Lock / Caps Lock as extra modifier bits.
+    let lock_variants = [0, xlib::Mod2Mask, xlib::LockMask, xlib::Mod2Mask | xlib::LockMask];
+    xlib::XUngrabKey(display, keycode as i32, xlib::AnyModifier, root);
+
+    // A chord already grabbed by another client is reported as an
+    // asynchronous BadAccess error rather than through XGrabKey's return
+    // value, so catch it with a temporary error handler and a round-trip.
+    static GRAB_DENIED: AtomicBool = AtomicBool::new(false);
+    unsafe extern "C" fn on_grab_error(_: *mut xlib::Display, error: *mut xlib::XErrorEvent) -> i32 {
+      if (*error).error_code == xlib::BadAccess {
+        GRAB_DENIED.store(true, Ordering::SeqCst);
+      }
+      0
+    }
+    GRAB_DENIED.store(false, Ordering::SeqCst);
+    let previous_handler = xlib::XSetErrorHandler(Some(on_grab_error));
+
+    let mut failed = false;
+    for variant in lock_variants {
+      let status = xlib::XGrabKey(
+        display,
+        keycode as i32,
+        modifiers | variant,
+        root,
+        xlib::True,
+        xlib::GrabModeAsync,
+        xlib::GrabModeAsync,
+      );
+      if status == 0 {
+        failed = true;
+      }
+    }
+    xlib::XSync(display, xlib::False);
+    xlib::XSetErrorHandler(previous_handler);
+    if GRAB_DENIED.load(Ordering::SeqCst) {
+      failed = true;
+    }
+
+    if failed {
+      xlib::XCloseDisplay(display);
+      return Err("Hotkey is already grabbed by another application".to_string());
+    }
+
+    GRABBED.store(true, Ordering::SeqCst);
+    // An unmapped 1x1 window owned by this connection, as the target of
+    // `unregister`'s wake-up event.
+    let wake_window = xlib::XCreateSimpleWindow(display, root, 0, 0, 1, 1, 0, 0, 0);
+    *WAKE_TARGET.lock().unwrap() = Some((display as usize, wake_window));
+
+    let app = app.clone();
+    let mode = mode;
+    let handle = std::thread::spawn(move || {
+      let mut event: xlib::XEvent = std::mem::zeroed();
+      loop {
+        if !GRABBED.load(Ordering::SeqCst) {
+          break;
+        }
+        xlib::XNextEvent(display, &mut event);
+        match event.get_type() {
+          xlib::KeyPress => super::on_hotkey_press(&app, mode),
+          xlib::KeyRelease => super::on_hotkey_release(&app, mode),
+          _ => {}
+        }
+      }
+      xlib::XCloseDisplay(display);
+    });
+
+    *WATCHER.lock().unwrap() = Some(handle);
+  }
+
+  Ok(())
+}
+
+pub fn unregister(_app: &AppHandle) {
+  GRABBED.store(false, Ordering::SeqCst);
+
+  // `XNextEvent` blocks until a real X event arrives, so simply flipping
+  // `GRABBED` leaves the watcher thread parked until the user happens to
+  // press the old chord again. Send it a harmless ClientMessage addressed
+  // at the wake window `register` created on the watcher's connection
+  // (with event_mask 0, X delivers the event to the window's creator) to
+  // unblock it immediately so it can re-check the flag.
+  if let Some((display_ptr, wake_window)) = WAKE_TARGET.lock().unwrap().take() {
+    unsafe {
+      let display = display_ptr as *mut xlib::Display;
+      let atom_name = CString::new("VOXLY_HOTKEY_WAKE").unwrap();
+      let wake_atom = xlib::XInternAtom(display, atom_name.as_ptr(), xlib::False);
+
+      let mut event: xlib::XClientMessageEvent = std::mem::zeroed();
+      event.type_ = xlib::ClientMessage;
+      event.window = wake_window;
+      event.message_type = wake_atom;
+      event.format = 32;
+
+      let mut xevent = xlib::XEvent { client_message: event };
+      xlib::XSendEvent(display, wake_window, xlib::False, 0, &mut xevent);
+      xlib::XFlush(display);
+    }
+  }
+
+  if let Some(handle) = WATCHER.lock().unwrap().take() {
+    let _ = handle.join();
+  }
+}
+
+fn parse_binding(binding: &HotkeyBinding) -> Result<(u32, xlib::KeySym), String> {
+  if binding.key.trim().is_empty() {
+    return Err("No key configured for global hotkey".to_string());
+  }
+
+  let mut modifiers = 0u32;
+  for modifier in &binding.modifiers {
+    modifiers |= match modifier.to_ascii_lowercase().as_str() {
+      "ctrl" | "control" => xlib::ControlMask,
+      "alt" => xlib::Mod1Mask,
+      "shift" => xlib::ShiftMask,
+      "super" | "meta" | "cmd" => xlib::Mod4Mask,
+      other => return Err(format!("Unknown modifier '{other}'")),
+    };
+  }
+
+  // Keysym names are case-sensitive ("space", not "Space").
+  let key_name = if binding.key.eq_ignore_ascii_case("space") {
+    "space".to_string()
+  } else {
+    binding.key.clone()
+  };
+  let keysym = unsafe {
+    let c_key = std::ffi::CString::new(key_name).map_err(|e| e.to_string())?;
+    xlib::XStringToKeysym(c_key.as_ptr())
+  };
+  if keysym == 0 {
+    return Err(format!("Unknown key '{}' for global hotkey", binding.key));
+  }
+
+  Ok((modifiers, keysym))
+}
@@ -0,0 +1,149 @@
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{mpsc, Mutex};
+
+use core_graphics::event::{CGEventTap, CGEventTapLocation, CGEventTapOptions, CGEventTapPlacement, CGEventType};
+use tauri::AppHandle;
+
+use crate::domain::types::{HotkeyBinding, HotkeyMode};
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static BOUND_KEYCODE: AtomicU32 = AtomicU32::new(u32::MAX);
+static BOUND_FLAGS: AtomicU32 = AtomicU32::new(0);
+static RUN_LOOP: Mutex<Option<std::thread::JoinHandle<()>>> = Mutex::new(None);
+
+/// A CGEventTap global monitor. Requires the accessibility permission the
+/// rest of the app already requests for clipboard paste, so no separate
+/// prompt is introduced here.
+pub fn register(app: &AppHandle, binding: &HotkeyBinding, mode: HotkeyMode) -> Result<(), String> {
+  let (flags, keycode) = parse_binding(binding)?;
+  BOUND_FLAGS.store(flags, Ordering::SeqCst);
+  BOUND_KEYCODE.store(keycode, Ordering::SeqCst);
+  ACTIVE.store(true, Ordering::SeqCst);
+
+  let (ready_tx, ready_rx) = mpsc::channel::<Result<(), String>>();
+  let app = app.clone();
+  let handle = std::thread::spawn(move || {
+    let current = core_foundation::runloop::CFRunLoop::get_current();
+
+    let tap = CGEventTap::new(
+      CGEventTapLocation::Session,
+      CGEventTapPlacement::HeadInsertEventTap,
+      CGEventTapOptions::ListenOnly,
+      vec![CGEventType::KeyDown, CGEventType::KeyUp],
+      move |_proxy, event_type, event| {
+        let keycode = event.get_integer_value_field(9) as u32; // kCGKeyboardEventKeycode
+        let flags = event.get_flags().bits() as u32;
+
+        if keycode == BOUND_KEYCODE.load(Ordering::SeqCst) && flags & BOUND_FLAGS.load(Ordering::SeqCst) == BOUND_FLAGS.load(Ordering::SeqCst) {
+          match event_type {
+            CGEventType::KeyDown => super::on_hotkey_press(&app, mode),
+            CGEventType::KeyUp => super::on_hotkey_release(&app, mode),
+            _ => {}
+          }
+        }
+
+        Some(event.to_owned())
+      },
+    );
+
+    let Ok(tap) = tap else {
+      let _ = ready_tx.send(Err(
+        "Failed to create CGEventTap for global hotkey — check Accessibility permission".to_string(),
+      ));
+      return;
+    };
+
+    unsafe {
+      let loop_source = tap.mach_port.create_runloop_source(0).expect("runloop source");
+      current.add_source(&loop_source, core_foundation::runloop::kCFRunLoopCommonModes);
+      tap.enable();
+    }
+
+    let _ = ready_tx.send(Ok(()));
+
+    while ACTIVE.load(Ordering::SeqCst) {
+      core_foundation::runloop::CFRunLoop::run_in_mode(
+        unsafe { core_foundation::runloop::kCFRunLoopDefaultMode },
+        std::time::Duration::from_millis(100),
+        false,
+      );
+    }
+  });
+
+  // Block until the background thread has actually created the event tap
+  // (or failed to), so a conflicting/denied hotkey surfaces as a real
+  // `Err` here instead of silently logging to stderr while `register`
+  // reports success.
+  match ready_rx.recv() {
+    Ok(Ok(())) => {
+      *RUN_LOOP.lock().unwrap() = Some(handle);
+      Ok(())
+    }
+    Ok(Err(e)) => {
+      ACTIVE.store(false, Ordering::SeqCst);
+      let _ = handle.join();
+      Err(e)
+    }
+    Err(_) => {
+      ACTIVE.store(false, Ordering::SeqCst);
+      let _ = handle.join();
+      Err("Global hotkey listener thread exited unexpectedly".to_string())
+    }
+  }
+}
+
+pub fn unregister(_app: &AppHandle) {
+  ACTIVE.store(false, Ordering::SeqCst);
+  if let Some(handle) = RUN_LOOP.lock().unwrap().take() {
+    let _ = handle.join();
+  }
+}
+
+fn parse_binding(binding: &HotkeyBinding) -> Result<(u32, u32), String> {
+  if binding.key.trim().is_empty() {
+    return Err("No key configured for global hotkey".to_string());
+  }
+
+  const MASK_CONTROL: u32 = 1 << 18;
+  const MASK_ALT: u32 = 1 << 19;
+  const MASK_SHIFT: u32 = 1 << 17;
+  const MASK_COMMAND: u32 = 1 << 20;
+
+  let mut flags = 0u32;
+  for modifier in &binding.modifiers {
+    flags |= match modifier.to_ascii_lowercase().as_str() {
+      "ctrl" | "control" => MASK_CONTROL,
+      "alt" | "option" => MASK_ALT,
+      "shift" => MASK_SHIFT,
+      "super" | "meta" | "cmd" | "command" => MASK_COMMAND,
+      other => return Err(format!("Unknown modifier '{other}'")),
+    };
+  }
+
+  let keycode = key_to_keycode(&binding.key)?;
+  Ok((flags, keycode))
+}
+
+fn key_to_keycode(key: &str) -> Result<u32, String> {
+  match key.to_ascii_uppercase().as_str() {
+    "SPACE" => Ok(49),
+    single if single.chars().count() == 1 => mac_keycode_for_letter(single.chars().next().unwrap()),
+    other => Err(format!("Unknown key '{other}' for global hotkey")),
+  }
+}
+
+fn mac_keycode_for_letter(c: char) -> Result<u32, String> {
+  // ANSI keyboard layout virtual keycodes for A-Z.
+  const LETTERS: [(char, u32); 26] = [
+    ('A', 0), ('S', 1), ('D', 2), ('F', 3), ('H', 4), ('G', 5), ('Z', 6), ('X', 7),
+    ('C', 8), ('V', 9), ('B', 11), ('Q', 12), ('W', 13), ('E', 14), ('R', 15), ('Y', 16),
+    ('T', 17), ('O', 31), ('U', 32), ('I', 34), ('P', 35), ('L', 37), ('J', 38), ('K', 40),
+    ('N', 45), ('M', 46),
+  ];
+  let upper = c.to_ascii_uppercase();
+  LETTERS
+    .iter()
+    .find(|(letter, _)| *letter == upper)
+    .map(|(_, code)| *code)
+    .ok_or_else(|| format!("Unknown key '{c}' for global hotkey"))
+}
@@ -0,0 +1,87 @@
+#[cfg(target_os = "windows")]
+mod windows;
+#[cfg(target_os = "macos")]
+mod macos;
+#[cfg(target_os = "linux")]
+mod linux;
+
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::domain::types::{HotkeyBinding, HotkeyMode};
+use crate::state::AppState;
+
+/// Grab `binding` at the OS level and drive `state.manager.start_recording`
+/// / `stop_and_process` on press/release according to `mode`. Returns a
+/// recoverable error (rather than panicking) if the chord is already owned
+/// by another process, so the settings UI can show it and let the user pick
+/// a different binding.
+pub fn register(app: &AppHandle, binding: &HotkeyBinding, mode: HotkeyMode) -> Result<(), String> {
+  unregister(app);
+
+  #[cfg(target_os = "windows")]
+  {
+    return windows::register(app, binding, mode);
+  }
+  #[cfg(target_os = "macos")]
+  {
+    return macos::register(app, binding, mode);
+  }
+  #[cfg(target_os = "linux")]
+  {
+    return linux::register(app, binding, mode);
+  }
+
+  #[allow(unreachable_code)]
+  Err("Global hotkeys are not supported on this platform".to_string())
+}
+
+pub fn unregister(app: &AppHandle) {
+  #[cfg(target_os = "windows")]
+  windows::unregister(app);
+  #[cfg(target_os = "macos")]
+  macos::unregister(app);
+  #[cfg(target_os = "linux")]
+  linux::unregister(app);
+
+  let _ = app;
+}
+
+/// Shared by every platform backend: start recording on press. In toggle
+/// mode, a press while already recording stops and transcribes instead.
+pub(crate) fn on_hotkey_press(app: &AppHandle, mode: HotkeyMode) {
+  let state = app.state::<AppState>();
+  if mode == HotkeyMode::Toggle && state.manager.is_recording() {
+    spawn_stop_and_process(app.clone());
+    return;
+  }
+
+  let window = app.get_webview_window("main");
+  let _ = state.manager.start_recording(move |update| {
+    if let Some(window) = &window {
+      let _ = window.emit("dictation:update", update);
+    }
+  });
+}
+
+/// Push-to-talk only: stop and transcribe when the chord is released.
+pub(crate) fn on_hotkey_release(app: &AppHandle, mode: HotkeyMode) {
+  if mode != HotkeyMode::PushToTalk {
+    return;
+  }
+  spawn_stop_and_process(app.clone());
+}
+
+fn spawn_stop_and_process(app: AppHandle) {
+  tauri::async_runtime::spawn(async move {
+    let state = app.state::<AppState>();
+    let window = app.get_webview_window("main");
+    let _ = state
+      .manager
+      .stop_and_process(|update| {
+        if let Some(window) = &window {
+          let _ = window.emit("dictation:update", update);
+        }
+      })
+      .await;
+  });
+}
@@ -0,0 +1,137 @@
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::mpsc;
+use std::sync::Mutex;
+
+use tauri::AppHandle;
+use windows::Win32::Foundation::WPARAM;
+use windows::Win32::System::Threading::GetCurrentThreadId;
+use windows::Win32::UI::Input::KeyboardAndMouse::{
+  RegisterHotKey, UnregisterHotKey, MOD_ALT, MOD_CONTROL, MOD_NOREPEAT, MOD_SHIFT, MOD_WIN, VK_SPACE,
+};
+use windows::Win32::UI::WindowsAndMessaging::{
+  GetMessageW, PostThreadMessageW, TranslateMessage, DispatchMessageW, MSG, WM_HOTKEY, WM_QUIT,
+};
+
+const HOTKEY_ID: i32 = 1;
+
+static REGISTERED: AtomicBool = AtomicBool::new(false);
+static LISTENER: Mutex<Option<std::thread::JoinHandle<()>>> = Mutex::new(None);
+static LISTENER_THREAD_ID: AtomicU32 = AtomicU32::new(0);
+static CURRENT_VK: AtomicU32 = AtomicU32::new(0);
+
+pub fn register(
+  app: &AppHandle,
+  binding: &crate::domain::types::HotkeyBinding,
+  mode: crate::domain::types::HotkeyMode,
+) -> Result<(), String> {
+  let (modifiers, vk) = parse_binding(binding)?;
+  CURRENT_VK.store(vk, Ordering::SeqCst);
+
+  let (ready_tx, ready_rx) = mpsc::channel::<Result<(), String>>();
+  let app = app.clone();
+  let handle = std::thread::spawn(move || unsafe {
+    LISTENER_THREAD_ID.store(GetCurrentThreadId(), Ordering::SeqCst);
+
+    if RegisterHotKey(None, HOTKEY_ID, modifiers | MOD_NOREPEAT, vk).is_err() {
+      let _ = ready_tx.send(Err("Hotkey is already registered by another application".to_string()));
+      return;
+    }
+    REGISTERED.store(true, Ordering::SeqCst);
+    let _ = ready_tx.send(Ok(()));
+
+    let mut msg = MSG::default();
+    while REGISTERED.load(Ordering::SeqCst) && GetMessageW(&mut msg, None, 0, 0).as_bool() {
+      if msg.message == WM_HOTKEY && msg.wParam == WPARAM(HOTKEY_ID as usize) {
+        super::on_hotkey_press(&app, mode);
+        if mode == crate::domain::types::HotkeyMode::PushToTalk {
+          wait_for_release(vk);
+          super::on_hotkey_release(&app, mode);
+        }
+      }
+      let _ = TranslateMessage(&msg);
+      DispatchMessageW(&msg);
+    }
+
+    let _ = UnregisterHotKey(None, HOTKEY_ID);
+  });
+
+  // Block until the background thread has actually grabbed the hotkey (or
+  // failed to), so a conflicting binding surfaces as a real `Err` here
+  // instead of silently logging to stderr while `register` reports success.
+  match ready_rx.recv() {
+    Ok(Ok(())) => {
+      *LISTENER.lock().unwrap() = Some(handle);
+      Ok(())
+    }
+    Ok(Err(e)) => {
+      let _ = handle.join();
+      Err(e)
+    }
+    Err(_) => {
+      let _ = handle.join();
+      Err("Global hotkey listener thread exited unexpectedly".to_string())
+    }
+  }
+}
+
+pub fn unregister(_app: &AppHandle) {
+  REGISTERED.store(false, Ordering::SeqCst);
+
+  // `GetMessageW` blocks until a real message arrives, so flipping
+  // `REGISTERED` alone leaves the listener thread parked until the next
+  // stray message. Post it a `WM_QUIT`, which `GetMessageW` always wakes
+  // up for, so it re-checks the flag and exits right away.
+  let thread_id = LISTENER_THREAD_ID.load(Ordering::SeqCst);
+  if thread_id != 0 {
+    unsafe {
+      let _ = PostThreadMessageW(thread_id, WM_QUIT, WPARAM(0), windows::Win32::Foundation::LPARAM(0));
+    }
+  }
+
+  if let Some(handle) = LISTENER.lock().unwrap().take() {
+    let _ = handle.join();
+  }
+}
+
+/// `RegisterHotKey` only fires on press, so push-to-talk release is
+/// detected by polling `GetAsyncKeyState` for the bound key going up.
+fn wait_for_release(vk: u32) {
+  use windows::Win32::UI::Input::KeyboardAndMouse::GetAsyncKeyState;
+  loop {
+    std::thread::sleep(std::time::Duration::from_millis(15));
+    let state = unsafe { GetAsyncKeyState(vk as i32) };
+    if state & 0x8000u16 as i16 == 0 {
+      break;
+    }
+  }
+}
+
+fn parse_binding(
+  binding: &crate::domain::types::HotkeyBinding,
+) -> Result<(windows::Win32::UI::Input::KeyboardAndMouse::HOT_KEY_MODIFIERS, u32), String> {
+  if binding.key.trim().is_empty() {
+    return Err("No key configured for global hotkey".to_string());
+  }
+
+  let mut modifiers = windows::Win32::UI::Input::KeyboardAndMouse::HOT_KEY_MODIFIERS(0);
+  for modifier in &binding.modifiers {
+    modifiers |= match modifier.to_ascii_lowercase().as_str() {
+      "ctrl" | "control" => MOD_CONTROL,
+      "alt" => MOD_ALT,
+      "shift" => MOD_SHIFT,
+      "super" | "meta" | "cmd" | "win" => MOD_WIN,
+      other => return Err(format!("Unknown modifier '{other}'")),
+    };
+  }
+
+  let vk = key_to_vk(&binding.key)?;
+  Ok((modifiers, vk))
+}
+
+fn key_to_vk(key: &str) -> Result<u32, String> {
+  match key.to_ascii_uppercase().as_str() {
+    "SPACE" => Ok(VK_SPACE.0 as u32),
+    single if single.chars().count() == 1 => Ok(single.chars().next().unwrap() as u32),
+    other => Err(format!("Unknown key '{other}' for global hotkey")),
+  }
+}
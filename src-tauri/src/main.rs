@@ -0,0 +1,76 @@
+#![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
+
+mod audio;
+mod captions;
+mod click_through;
+mod clipboard;
+mod commands;
+mod domain;
+mod format_text;
+mod hotkey;
+mod models_api;
+mod settings;
+mod speech;
+mod state;
+mod streaming_transcribe;
+mod transcribe;
+mod transcription_history;
+mod vocabulary_filter;
+
+use tauri::Manager;
+
+use state::AppState;
+
+fn main() {
+  tauri::Builder::default()
+    .manage(AppState::default())
+    .setup(|app| {
+      let app_handle = app.handle().clone();
+
+      // Re-grab whatever hotkey binding was persisted from the previous
+      // run, so global capture survives an app restart.
+      let state = app_handle.state::<AppState>();
+      if let Ok(settings) = state.manager.get_settings() {
+        if let Some(binding) = &settings.hotkey {
+          if let Err(e) = hotkey::register(&app_handle, binding, settings.hotkey_mode) {
+            eprintln!("Failed to restore saved global hotkey: {e}");
+          }
+        }
+      }
+
+      if let Some(window) = app.get_webview_window("main") {
+        commands::ensure_main_visible(&window);
+      }
+
+      commands::start_audio_level_emitter(&app_handle);
+
+      Ok(())
+    })
+    .invoke_handler(tauri::generate_handler![
+      commands::update_hit_region,
+      commands::start_recording,
+      commands::stop_and_transcribe,
+      commands::cancel_transcription,
+      commands::start_streaming,
+      commands::stop_streaming,
+      commands::get_settings,
+      commands::save_settings,
+      commands::speak_text,
+      commands::stop_speaking,
+      commands::list_voices,
+      commands::update_hotkey,
+      commands::clear_hotkey,
+      commands::save_vocabulary,
+      commands::export_captions,
+      commands::test_connection,
+      commands::fetch_provider_models,
+      commands::get_transcription_history,
+      commands::delete_transcription_history_item,
+      commands::clear_transcription_history,
+      commands::position_window_bottom,
+      commands::show_settings_window,
+      commands::hide_settings_window,
+    ])
+    .run(tauri::generate_context!())
+    .expect("error while running tauri application");
+}
@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::types::{
+  HotkeyBinding, HotkeyMode, PasteMode, StabilityLevel, VadSettings, VocabularyEntry, VocabularyFilterSettings,
+};
+
+fn default_direct_type_delay_ms() -> u64 {
+  8
+}
+
+fn default_streaming_latency_ms() -> u64 {
+  2_000
+}
+
+fn default_tts_rate() -> f32 {
+  1.0
+}
+
+fn default_tts_pitch() -> f32 {
+  1.0
+}
+
+fn default_tts_volume() -> f32 {
+  1.0
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppSettings {
+  #[serde(default)]
+  pub provider: String,
+  #[serde(default)]
+  pub base_url: String,
+  #[serde(default)]
+  pub api_key: String,
+  #[serde(default)]
+  pub model: String,
+  #[serde(default)]
+  pub vocabulary: Vec<VocabularyEntry>,
+  #[serde(default)]
+  pub vocabulary_filter: VocabularyFilterSettings,
+
+  #[serde(default)]
+  pub tts_voice_id: Option<String>,
+  #[serde(default = "default_tts_rate")]
+  pub tts_rate: f32,
+  #[serde(default = "default_tts_pitch")]
+  pub tts_pitch: f32,
+  #[serde(default = "default_tts_volume")]
+  pub tts_volume: f32,
+
+  /// Whether transcribed text should be passed through the LLM formatter
+  /// before pasting.
+  #[serde(default)]
+  pub format_enabled: bool,
+  #[serde(default)]
+  pub format_system_prompt: String,
+
+  #[serde(default)]
+  pub hotkey: Option<HotkeyBinding>,
+  #[serde(default)]
+  pub hotkey_mode: HotkeyMode,
+
+  #[serde(default)]
+  pub paste_mode: PasteMode,
+  #[serde(default = "default_direct_type_delay_ms")]
+  pub direct_type_delay_ms: u64,
+
+  #[serde(default)]
+  pub vad: VadSettings,
+
+  #[serde(default = "default_streaming_latency_ms")]
+  pub streaming_latency_ms: u64,
+  #[serde(default)]
+  pub streaming_stability: StabilityLevel,
+}
+
+impl Default for AppSettings {
+  fn default() -> Self {
+    Self {
+      provider: "openai".to_string(),
+      base_url: String::new(),
+      api_key: String::new(),
+      model: String::new(),
+      vocabulary: Vec::new(),
+      vocabulary_filter: VocabularyFilterSettings::default(),
+      tts_voice_id: None,
+      tts_rate: default_tts_rate(),
+      tts_pitch: default_tts_pitch(),
+      tts_volume: default_tts_volume(),
+      format_enabled: false,
+      format_system_prompt: String::new(),
+      hotkey: None,
+      hotkey_mode: HotkeyMode::Toggle,
+      paste_mode: PasteMode::ClipboardPaste,
+      direct_type_delay_ms: default_direct_type_delay_ms(),
+      vad: VadSettings::default(),
+      streaming_latency_ms: default_streaming_latency_ms(),
+      streaming_stability: StabilityLevel::default(),
+    }
+  }
+}
+
+const SETTINGS_FILE: &str = "settings.json";
+
+fn settings_path() -> Option<std::path::PathBuf> {
+  let dirs = directories::ProjectDirs::from("dev", "Voxly", "Voxly")?;
+  Some(dirs.config_dir().join(SETTINGS_FILE))
+}
+
+pub fn load_settings() -> AppSettings {
+  let Some(path) = settings_path() else {
+    return AppSettings::default();
+  };
+
+  let Ok(raw) = std::fs::read_to_string(&path) else {
+    return AppSettings::default();
+  };
+
+  serde_json::from_str(&raw).unwrap_or_default()
+}
+
+pub fn save_settings(settings: &AppSettings) -> Result<(), String> {
+  let path = settings_path().ok_or("Could not determine settings directory".to_string())?;
+
+  if let Some(parent) = path.parent() {
+    std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+  }
+
+  let json = serde_json::to_string_pretty(settings).map_err(|e| e.to_string())?;
+  std::fs::write(&path, json).map_err(|e| e.to_string())?;
+  Ok(())
+}
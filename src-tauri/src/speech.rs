@@ -0,0 +1,124 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use tts::Tts;
+
+use crate::domain::types::{SpeechEvent, SpeechPhase, VoiceInfo};
+
+type EventCallback = Box<dyn FnMut(SpeechEvent) + Send>;
+
+/// Wraps the cross-platform `tts` crate, which speaks through
+/// SpeechDispatcher/libspeechd on Linux, SAPI on Windows, and
+/// AVSpeechSynthesizer on macOS.
+pub struct SpeechEngine {
+  tts: Mutex<Tts>,
+  next_id: AtomicU64,
+  current_utterance: Arc<Mutex<Option<(u64, EventCallback)>>>,
+}
+
+impl SpeechEngine {
+  pub fn new() -> Result<Self, String> {
+    let mut tts = Tts::default().map_err(|e| format!("Failed to initialize TTS engine: {e}"))?;
+    let current_utterance: Arc<Mutex<Option<(u64, EventCallback)>>> = Arc::new(Mutex::new(None));
+
+    let begin_slot = current_utterance.clone();
+    tts.on_utterance_begin(Some(Box::new(move |_id| {
+      notify(&begin_slot, SpeechPhase::Begin);
+    })));
+
+    let end_slot = current_utterance.clone();
+    tts.on_utterance_end(Some(Box::new(move |_id| {
+      notify(&end_slot, SpeechPhase::End);
+    })));
+
+    Ok(Self {
+      tts: Mutex::new(tts),
+      next_id: AtomicU64::new(1),
+      current_utterance,
+    })
+  }
+
+  pub fn speak(&self, text: &str, interrupt: bool, on_event: EventCallback) -> Result<u64, String> {
+    let utterance_id = self.next_id.fetch_add(1, Ordering::SeqCst);
+
+    {
+      let mut slot = self
+        .current_utterance
+        .lock()
+        .map_err(|_| "Speech callback lock poisoned".to_string())?;
+      *slot = Some((utterance_id, on_event));
+    }
+
+    let mut tts = self.tts.lock().map_err(|_| "TTS engine lock poisoned".to_string())?;
+    tts
+      .speak(text, interrupt)
+      .map_err(|e| format!("Failed to speak text: {e}"))?;
+
+    Ok(utterance_id)
+  }
+
+  pub fn stop_speaking(&self) -> Result<(), String> {
+    let mut tts = self.tts.lock().map_err(|_| "TTS engine lock poisoned".to_string())?;
+    tts.stop().map_err(|e| format!("Failed to stop speech: {e}"))
+  }
+
+  /// Linux's SpeechDispatcher backend panics on `.voices()` when no voices
+  /// are installed; fall back to an empty list instead of unwrapping.
+  pub fn list_voices(&self) -> Vec<VoiceInfo> {
+    let tts = match self.tts.lock() {
+      Ok(tts) => tts,
+      Err(_) => return Vec::new(),
+    };
+
+    tts
+      .voices()
+      .unwrap_or_default()
+      .into_iter()
+      .map(|voice| VoiceInfo {
+        id: voice.id(),
+        language: voice.language().to_string(),
+        name: voice.name(),
+      })
+      .collect()
+  }
+
+  pub fn set_rate(&self, rate: f32) -> Result<(), String> {
+    let mut tts = self.tts.lock().map_err(|_| "TTS engine lock poisoned".to_string())?;
+    tts.set_rate(rate).map_err(|e| format!("Failed to set rate: {e}"))
+  }
+
+  pub fn set_pitch(&self, pitch: f32) -> Result<(), String> {
+    let mut tts = self.tts.lock().map_err(|_| "TTS engine lock poisoned".to_string())?;
+    tts.set_pitch(pitch).map_err(|e| format!("Failed to set pitch: {e}"))
+  }
+
+  pub fn set_volume(&self, volume: f32) -> Result<(), String> {
+    let mut tts = self.tts.lock().map_err(|_| "TTS engine lock poisoned".to_string())?;
+    tts
+      .set_volume(volume)
+      .map_err(|e| format!("Failed to set volume: {e}"))
+  }
+
+  pub fn set_voice(&self, voice_id: &str) -> Result<(), String> {
+    let mut tts = self.tts.lock().map_err(|_| "TTS engine lock poisoned".to_string())?;
+    let voice = tts
+      .voices()
+      .unwrap_or_default()
+      .into_iter()
+      .find(|voice| voice.id() == voice_id)
+      .ok_or_else(|| format!("No installed voice with id {voice_id}"))?;
+    tts.set_voice(&voice).map_err(|e| format!("Failed to set voice: {e}"))
+  }
+}
+
+fn notify(slot: &Arc<Mutex<Option<(u64, EventCallback)>>>, phase: SpeechPhase) {
+  let Ok(mut guard) = slot.lock() else {
+    return;
+  };
+  if let Some((utterance_id, callback)) = guard.as_mut() {
+    callback(SpeechEvent {
+      utterance_id: *utterance_id,
+      phase,
+    });
+  }
+}
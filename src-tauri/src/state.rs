@@ -1,6 +1,10 @@
 use crate::domain::{
-  impls::{ClipboardPaster, CpalRecorder, FileAndKeyringSettingsStore, OpenAiCompatibleTranscriber},
+  impls::{
+    ClipboardPaster, CpalRecorder, FileAndKeyringSettingsStore, OpenAiCompatibleFormatter,
+    OpenAiCompatibleStreamingTranscriber, OpenAiCompatibleTranscriber, SystemSpeaker,
+  },
   manager::DictationSessionManager,
+  ports::{Formatter, Paster, Recorder, Speaker, SettingsStore, StreamingTranscriber, Transcriber},
 };
 
 pub struct AppState {
@@ -9,13 +13,160 @@ pub struct AppState {
 
 impl Default for AppState {
   fn default() -> Self {
+    Self::production()
+  }
+}
+
+impl AppState {
+  /// Real backends: CPAL microphone capture, the OpenAI-compatible HTTP
+  /// transcriber (buffered and streaming), the OS clipboard/keystroke
+  /// paster, the JSON-file settings store, and the platform speech
+  /// engine.
+  pub fn production() -> Self {
+    let speaker: Box<dyn Speaker> = match SystemSpeaker::new() {
+      Ok(speaker) => Box::new(speaker),
+      Err(e) => {
+        eprintln!("Failed to initialize speech engine: {e}");
+        Box::new(crate::domain::impls::NullSpeaker)
+      }
+    };
+
+    Self::with_backends(
+      Box::new(CpalRecorder::default()),
+      Box::new(FileAndKeyringSettingsStore),
+      Box::new(OpenAiCompatibleTranscriber),
+      Box::new(OpenAiCompatibleStreamingTranscriber),
+      Box::new(ClipboardPaster),
+      speaker,
+      Box::new(OpenAiCompatibleFormatter),
+    )
+  }
+
+  /// In-memory mock backends for exercising the dictation flow in tests
+  /// without real audio hardware, network, or OS clipboard access.
+  #[cfg(test)]
+  pub fn test() -> Self {
+    use crate::domain::mocks::{
+      CapturingPaster, InMemorySettingsStore, MockFormatter, MockRecorder, MockSpeaker, MockStreamingTranscriber,
+      MockTranscriber,
+    };
+
+    Self::with_backends(
+      Box::new(MockRecorder::new(Vec::new())),
+      Box::new(InMemorySettingsStore::default()),
+      Box::new(MockTranscriber::with_text("")),
+      Box::new(MockStreamingTranscriber::new(Vec::new())),
+      Box::new(CapturingPaster::new()),
+      Box::new(MockSpeaker::default()),
+      Box::new(MockFormatter),
+    )
+  }
+
+  pub fn with_backends(
+    recorder: Box<dyn Recorder>,
+    settings_store: Box<dyn SettingsStore>,
+    transcriber: Box<dyn Transcriber>,
+    streaming_transcriber: Box<dyn StreamingTranscriber>,
+    paster: Box<dyn Paster>,
+    speaker: Box<dyn Speaker>,
+    formatter: Box<dyn Formatter>,
+  ) -> Self {
     Self {
       manager: DictationSessionManager::new(
-        Box::new(CpalRecorder::default()),
-        Box::new(FileAndKeyringSettingsStore),
-        Box::new(OpenAiCompatibleTranscriber),
-        Box::new(ClipboardPaster),
+        recorder,
+        settings_store,
+        transcriber,
+        streaming_transcriber,
+        paster,
+        speaker,
+        formatter,
       ),
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::mocks::{
+    CapturingPaster, InMemorySettingsStore, MockFormatter, MockRecorder, MockSpeaker, MockStreamingTranscriber,
+    MockTranscriber,
+  };
+  use crate::domain::types::DictationState;
+
+  fn drive_to_done(state: &AppState) -> (Vec<DictationState>, String) {
+    let observed = std::sync::Mutex::new(Vec::new());
+
+    state
+      .manager
+      .start_recording(|update| observed.lock().unwrap().push(update.state))
+      .expect("start_recording should succeed with mock backends");
+
+    let text = tauri::async_runtime::block_on(
+      state
+        .manager
+        .stop_and_process(|update| observed.lock().unwrap().push(update.state)),
+    )
+    .expect("stop_and_process should succeed with mock backends");
+
+    (observed.into_inner().unwrap(), text)
+  }
+
+  fn mock_state_with(
+    recorder: MockRecorder,
+    transcriber: MockTranscriber,
+    paster: std::sync::Arc<CapturingPaster>,
+  ) -> AppState {
+    AppState::with_backends(
+      Box::new(recorder),
+      Box::new(InMemorySettingsStore::default()),
+      Box::new(transcriber),
+      Box::new(MockStreamingTranscriber::new(Vec::new())),
+      Box::new(paster),
+      Box::new(MockSpeaker::default()),
+      Box::new(MockFormatter),
+    )
+  }
+
+  #[test]
+  fn dictation_flow_emits_expected_state_sequence() {
+    let state = mock_state_with(
+      MockRecorder::new(b"fake-wav-bytes".to_vec()),
+      MockTranscriber::with_text("hello world"),
+      std::sync::Arc::new(CapturingPaster::new()),
+    );
+
+    let (states, text) = drive_to_done(&state);
+
+    assert_eq!(
+      states,
+      vec![
+        DictationState::Recording,
+        DictationState::Transcribing,
+        DictationState::Pasting,
+        DictationState::Done,
+      ]
+    );
+    assert_eq!(text, "hello world");
+  }
+
+  #[test]
+  fn dictation_flow_pastes_the_transcribed_text() {
+    let paster = std::sync::Arc::new(CapturingPaster::new());
+    let state = mock_state_with(
+      MockRecorder::new(Vec::new()),
+      MockTranscriber::with_text("dictated text"),
+      paster.clone(),
+    );
+
+    drive_to_done(&state);
+
+    assert_eq!(paster.last_pasted().as_deref(), Some("dictated text"));
+  }
+
+  #[test]
+  fn test_backends_drive_the_flow_without_touching_the_os() {
+    let state = AppState::test();
+    drive_to_done(&state);
+  }
+}
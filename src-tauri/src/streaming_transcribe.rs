@@ -0,0 +1,141 @@
+use futures_util::{SinkExt, StreamExt};
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::domain::types::{StreamingItem, StreamingPartial};
+
+/// Opens a persistent connection to a streaming transcription endpoint,
+/// pushes audio frames as they are captured, and resolves to the final
+/// committed text once `audio_frames` closes. A fresh connection is built
+/// for every call rather than reused, so a dropped connection just means
+/// the next run starts clean.
+pub async fn transcribe_streaming(
+  base_url: &str,
+  api_key: &str,
+  model: &str,
+  mut audio_frames: mpsc::Receiver<Vec<u8>>,
+  mut on_partial: impl FnMut(StreamingPartial) + Send + 'static,
+) -> Result<String, String> {
+  if api_key.trim().is_empty() {
+    return Err("Missing API key".to_string());
+  }
+
+  let url = build_streaming_url(base_url);
+  let mut request = url
+    .clone()
+    .into_client_request()
+    .map_err(|e| format!("Invalid streaming URL '{url}': {e}"))?;
+  request
+    .headers_mut()
+    .insert("Authorization", format!("Bearer {api_key}").parse().map_err(|e| format!("{e}"))?);
+
+  let (ws_stream, _) = tokio_tungstenite::connect_async(request)
+    .await
+    .map_err(|e| format!("Failed to open streaming connection: {e}"))?;
+
+  let (mut write, mut read) = ws_stream.split();
+
+  write
+    .send(Message::text(
+      serde_json::json!({ "type": "session.start", "model": model }).to_string(),
+    ))
+    .await
+    .map_err(|e| format!("Failed to start streaming session: {e}"))?;
+
+  // Dedicated send loop: forwards captured audio frames to the socket as
+  // they arrive, independent of the receive loop below.
+  let send_loop = tokio::spawn(async move {
+    while let Some(chunk) = audio_frames.recv().await {
+      if write.send(Message::binary(chunk)).await.is_err() {
+        break;
+      }
+    }
+    let _ = write.send(Message::text(serde_json::json!({ "type": "session.end" }).to_string())).await;
+    let _ = write.close().await;
+  });
+
+  let mut final_text = String::new();
+
+  while let Some(message) = read.next().await {
+    let message = message.map_err(|e| format!("Streaming connection error: {e}"))?;
+    let Message::Text(text) = message else {
+      continue;
+    };
+
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(&text) else {
+      continue;
+    };
+
+    let Some(raw_items) = json["items"].as_array() else {
+      continue;
+    };
+    let is_final = json["is_final"].as_bool().unwrap_or(false);
+
+    let items: Vec<StreamingItem> = raw_items
+      .iter()
+      .filter_map(|raw| {
+        Some(StreamingItem {
+          start_time: raw["start_time"].as_f64()?,
+          end_time: raw["end_time"].as_f64()?,
+          text: raw["text"].as_str()?.to_string(),
+          stable: raw["stable"].as_bool().unwrap_or(false),
+        })
+      })
+      .collect();
+
+    if is_final {
+      final_text = items.iter().map(|item| item.text.as_str()).collect::<Vec<_>>().join(" ");
+    }
+
+    on_partial(StreamingPartial { items, is_final });
+  }
+
+  let _ = send_loop.await;
+
+  Ok(final_text)
+}
+
+/// Mirrors `transcribe::build_transcription_url`'s normalization, but
+/// targets the streaming endpoint over a WebSocket scheme.
+fn build_streaming_url(base_url: &str) -> String {
+  let trimmed = base_url.trim_end_matches('/');
+  let ws_base = if let Some(rest) = trimmed.strip_prefix("https://") {
+    format!("wss://{rest}")
+  } else if let Some(rest) = trimmed.strip_prefix("http://") {
+    format!("ws://{rest}")
+  } else {
+    trimmed.to_string()
+  };
+
+  if ws_base.ends_with("/audio/transcriptions/stream") {
+    ws_base
+  } else {
+    format!("{ws_base}/audio/transcriptions/stream")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::build_streaming_url;
+
+  #[test]
+  fn url_builder_swaps_scheme_and_appends_stream_path() {
+    assert_eq!(
+      build_streaming_url("https://api.openai.com/v1"),
+      "wss://api.openai.com/v1/audio/transcriptions/stream"
+    );
+    assert_eq!(
+      build_streaming_url("http://localhost:8080/v1/"),
+      "ws://localhost:8080/v1/audio/transcriptions/stream"
+    );
+  }
+
+  #[test]
+  fn url_builder_accepts_full_endpoint() {
+    assert_eq!(
+      build_streaming_url("wss://api.openai.com/v1/audio/transcriptions/stream"),
+      "wss://api.openai.com/v1/audio/transcriptions/stream"
+    );
+  }
+}
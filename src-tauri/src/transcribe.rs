@@ -1,6 +1,12 @@
+use std::time::Duration;
+
 use reqwest::multipart;
 use serde::{Deserialize, Serialize};
 
+/// Upper bound on a single transcription HTTP request, so a stalled
+/// connection is torn down rather than left hanging indefinitely.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TranscriptionSegment {
   pub start: f64,
@@ -13,7 +19,6 @@ pub struct TranscriptionResult {
   pub text: String,
   pub duration_secs: Option<f64>,
   pub language: Option<String>,
-  #[allow(dead_code)] // Parsed from API, not yet stored in history — future use
   pub segments: Option<Vec<TranscriptionSegment>>,
 }
 
@@ -168,6 +173,7 @@ async fn send_transcription_request(
     .post(url)
     .bearer_auth(api_key)
     .multipart(form)
+    .timeout(REQUEST_TIMEOUT)
     .send()
     .await
     .map_err(|error| ApiError::transport(error.to_string()))?;
@@ -0,0 +1,108 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+/// Keep the history bounded so the file doesn't grow forever on a machine
+/// that's been dictating for months.
+const MAX_HISTORY_ITEMS: usize = 200;
+const HISTORY_FILE: &str = "transcription_history.json";
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+/// Set when a background-ish write (`record_transcription`, which swallows
+/// its own error so a history-file hiccup never fails the dictation it's
+/// logging) fails, so the next command round-trip can surface it to the UI.
+static RUNTIME_ERROR: Lazy<Mutex<Option<String>>> = Lazy::new(|| Mutex::new(None));
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscriptionHistoryItem {
+  pub id: String,
+  pub text: String,
+  pub language: Option<String>,
+  pub duration_secs: Option<f64>,
+  pub created_at_unix_ms: u64,
+}
+
+fn history_path() -> Option<std::path::PathBuf> {
+  let dirs = directories::ProjectDirs::from("dev", "Voxly", "Voxly")?;
+  Some(dirs.config_dir().join(HISTORY_FILE))
+}
+
+fn read_history() -> Vec<TranscriptionHistoryItem> {
+  let Some(path) = history_path() else {
+    return Vec::new();
+  };
+
+  let Ok(raw) = std::fs::read_to_string(&path) else {
+    return Vec::new();
+  };
+
+  serde_json::from_str(&raw).unwrap_or_default()
+}
+
+fn write_history(items: &[TranscriptionHistoryItem]) -> Result<(), String> {
+  let path = history_path().ok_or("Could not determine history directory".to_string())?;
+
+  if let Some(parent) = path.parent() {
+    std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+  }
+
+  let json = serde_json::to_string_pretty(items).map_err(|e| e.to_string())?;
+  std::fs::write(&path, json).map_err(|e| e.to_string())
+}
+
+pub fn load_history() -> Result<Vec<TranscriptionHistoryItem>, String> {
+  Ok(read_history())
+}
+
+/// Appends a completed transcription to the history file. Swallows its own
+/// error (stashing it for `take_runtime_error` instead) so a history-file
+/// hiccup never fails the dictation that produced `text`.
+pub fn record_transcription(text: &str, language: Option<String>, duration_secs: Option<f64>) {
+  if text.trim().is_empty() {
+    return;
+  }
+
+  if let Err(e) = try_record_transcription(text, language, duration_secs) {
+    *RUNTIME_ERROR.lock().unwrap() = Some(e);
+  }
+}
+
+fn try_record_transcription(text: &str, language: Option<String>, duration_secs: Option<f64>) -> Result<(), String> {
+  let created_at_unix_ms = std::time::SystemTime::now()
+    .duration_since(std::time::UNIX_EPOCH)
+    .map(|d| d.as_millis() as u64)
+    .unwrap_or(0);
+
+  let mut items = read_history();
+  items.push(TranscriptionHistoryItem {
+    id: format!("{created_at_unix_ms}-{}", NEXT_ID.fetch_add(1, Ordering::SeqCst)),
+    text: text.to_string(),
+    language,
+    duration_secs,
+    created_at_unix_ms,
+  });
+
+  if items.len() > MAX_HISTORY_ITEMS {
+    let overflow = items.len() - MAX_HISTORY_ITEMS;
+    items.drain(0..overflow);
+  }
+
+  write_history(&items)
+}
+
+pub fn delete_item(id: &str) -> Result<(), String> {
+  let mut items = read_history();
+  items.retain(|item| item.id != id);
+  write_history(&items)
+}
+
+pub fn clear_history() -> Result<(), String> {
+  write_history(&[])
+}
+
+/// Drains whatever error `record_transcription` last stashed, if any.
+pub fn take_runtime_error() -> Option<String> {
+  RUNTIME_ERROR.lock().unwrap().take()
+}
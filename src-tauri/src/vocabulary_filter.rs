@@ -0,0 +1,168 @@
+use crate::domain::types::{VocabularyFilterMethod, VocabularyFilterSettings};
+
+/// Scrubs configured words/phrases from transcribed text before it's
+/// pasted. Matching is case-insensitive and whole-word, so a configured
+/// entry never clips a substring of an unrelated word. Multi-word phrases
+/// are matched longest-first so they take precedence over any single word
+/// they contain.
+pub fn apply_vocabulary_filter(text: &str, settings: &VocabularyFilterSettings) -> String {
+  if !settings.enabled || settings.words.is_empty() {
+    return text.to_string();
+  }
+
+  let mut phrases: Vec<&str> = settings
+    .words
+    .iter()
+    .map(|word| word.trim())
+    .filter(|word| !word.is_empty())
+    .collect();
+  phrases.sort_by_key(|phrase| std::cmp::Reverse(phrase.len()));
+
+  let mut result = text.to_string();
+  for phrase in phrases {
+    result = replace_whole_word(&result, phrase, settings.method, &settings.tag_marker);
+  }
+
+  if settings.method == VocabularyFilterMethod::Remove {
+    result = result.split_whitespace().collect::<Vec<_>>().join(" ");
+  }
+
+  result
+}
+
+/// Finds non-overlapping, case-insensitive whole-word occurrences of
+/// `phrase` in `haystack` and rewrites each one according to `method`.
+///
+/// Matching walks `haystack` char-by-char and case-folds each candidate
+/// window against `phrase` in place, rather than searching a separately
+/// lowercased copy of `haystack` and reusing its byte offsets: some
+/// characters' lowercase form is a different UTF-8 byte length than the
+/// original (e.g. Turkish `İ`), which would desynchronize offsets taken
+/// from one string and applied to the other.
+fn replace_whole_word(haystack: &str, phrase: &str, method: VocabularyFilterMethod, tag_marker: &str) -> String {
+  let phrase_char_count = phrase.chars().count();
+  if phrase_char_count == 0 {
+    return haystack.to_string();
+  }
+
+  let indices: Vec<usize> = haystack.char_indices().map(|(i, _)| i).chain([haystack.len()]).collect();
+
+  let mut output = String::with_capacity(haystack.len());
+  let mut cursor = 0usize;
+
+  for window_start in 0..indices.len().saturating_sub(1) {
+    let start = indices[window_start];
+    if start < cursor {
+      continue;
+    }
+
+    let Some(&end) = indices.get(window_start + phrase_char_count) else {
+      break;
+    };
+
+    if !case_insensitive_eq(&haystack[start..end], phrase) {
+      continue;
+    }
+
+    let left_is_boundary = haystack[..start].chars().next_back().map_or(true, |c| !c.is_alphanumeric());
+    let right_is_boundary = haystack[end..].chars().next().map_or(true, |c| !c.is_alphanumeric());
+    if !left_is_boundary || !right_is_boundary {
+      continue;
+    }
+
+    output.push_str(&haystack[cursor..start]);
+    let matched = &haystack[start..end];
+    match method {
+      VocabularyFilterMethod::Mask => output.push_str(&"*".repeat(matched.chars().count())),
+      VocabularyFilterMethod::Remove => {}
+      VocabularyFilterMethod::Tag => output.push_str(&tag_marker.replace("{}", matched)),
+    }
+    cursor = end;
+  }
+
+  output.push_str(&haystack[cursor..]);
+  output
+}
+
+/// Full Unicode case-insensitive comparison (beyond ASCII), used instead
+/// of `eq_ignore_ascii_case`, which only folds the ASCII range.
+fn case_insensitive_eq(a: &str, b: &str) -> bool {
+  a.chars().flat_map(char::to_lowercase).eq(b.chars().flat_map(char::to_lowercase))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn settings(method: VocabularyFilterMethod, words: &[&str]) -> VocabularyFilterSettings {
+    VocabularyFilterSettings {
+      enabled: true,
+      method,
+      words: words.iter().map(|w| w.to_string()).collect(),
+      tag_marker: "[{}]".to_string(),
+    }
+  }
+
+  #[test]
+  fn mask_replaces_matched_characters_preserving_length() {
+    let result = apply_vocabulary_filter("that darn bug again", &settings(VocabularyFilterMethod::Mask, &["darn"]));
+    assert_eq!(result, "that **** bug again");
+  }
+
+  #[test]
+  fn remove_deletes_the_match_and_collapses_whitespace() {
+    let result = apply_vocabulary_filter("that darn bug again", &settings(VocabularyFilterMethod::Remove, &["darn"]));
+    assert_eq!(result, "that bug again");
+  }
+
+  #[test]
+  fn tag_wraps_the_match_in_the_configured_marker() {
+    let result = apply_vocabulary_filter("that darn bug again", &settings(VocabularyFilterMethod::Tag, &["darn"]));
+    assert_eq!(result, "that [darn] bug again");
+  }
+
+  #[test]
+  fn matching_is_case_insensitive() {
+    let result = apply_vocabulary_filter("DARN it", &settings(VocabularyFilterMethod::Mask, &["darn"]));
+    assert_eq!(result, "**** it");
+  }
+
+  #[test]
+  fn matching_is_whole_word_only() {
+    let result = apply_vocabulary_filter("the darnest thing", &settings(VocabularyFilterMethod::Mask, &["darn"]));
+    assert_eq!(result, "the darnest thing");
+  }
+
+  #[test]
+  fn matches_multi_word_phrases() {
+    let result = apply_vocabulary_filter(
+      "my social security number is secret",
+      &settings(VocabularyFilterMethod::Tag, &["social security number"]),
+    );
+    assert_eq!(result, "my [social security number] is secret");
+  }
+
+  #[test]
+  fn longer_phrases_take_precedence_over_words_they_contain() {
+    let result = apply_vocabulary_filter(
+      "social security number leak",
+      &settings(VocabularyFilterMethod::Mask, &["security", "social security number"]),
+    );
+    assert_eq!(result, "********************** leak");
+  }
+
+  #[test]
+  fn disabled_filter_leaves_text_untouched() {
+    let mut disabled = settings(VocabularyFilterMethod::Mask, &["darn"]);
+    disabled.enabled = false;
+    assert_eq!(apply_vocabulary_filter("that darn bug", &disabled), "that darn bug");
+  }
+
+  #[test]
+  fn handles_characters_whose_lowercase_form_changes_byte_length() {
+    // Turkish capital dotted İ lowercases to the two-codepoint sequence
+    // "i̇", which is longer in UTF-8 bytes than the original character.
+    let result = apply_vocabulary_filter("İstanbul darn city", &settings(VocabularyFilterMethod::Mask, &["darn"]));
+    assert_eq!(result, "İstanbul **** city");
+  }
+}